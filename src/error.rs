@@ -650,7 +650,8 @@ where
         F: FnOnce(&mut Tracked<Self>),
     {
         f(self_);
-        self_.error = info;
+        // Keep reporting end of input so it is still possible to tell whether more input may help
+        ParseError::<Item, Range, Position>::add(&mut self_.error, info);
     }
 
     fn is_unexpected_end_of_input(&self) -> bool {
@@ -805,7 +806,8 @@ where
         F: FnOnce(&mut Tracked<Self>),
     {
         f(self_);
-        self_.error = info;
+        // Keep reporting end of input so it is still possible to tell whether more input may help
+        ParseError::<Item, Range, Position>::add(&mut self_.error, info);
     }
 
     fn is_unexpected_end_of_input(&self) -> bool {
@@ -977,7 +979,11 @@ impl<O, E> From<StdParseResult2<O, E>> for ParseResult<O, E> {
 #[cfg(all(feature = "std", test))]
 mod tests_std {
 
-    use crate::Parser;
+    use crate::{
+        error::{ParseError, StringStreamError},
+        parser::char::digit,
+        EasyParser, Parser,
+    };
 
     #[derive(Clone, PartialEq, Debug)]
     struct CloneOnly {
@@ -1001,4 +1007,22 @@ mod tests_std {
             ))
         );
     }
+
+    #[test]
+    fn end_of_input_is_distinguishable() {
+        let err = digit().easy_parse("").unwrap_err();
+        assert!(err.is_unexpected_end_of_input());
+        assert!(
+            err.to_string().contains("Unexpected `end of input`"),
+            "{}",
+            err
+        );
+
+        assert!(!digit()
+            .easy_parse("a")
+            .unwrap_err()
+            .is_unexpected_end_of_input());
+
+        assert_eq!(digit().parse(""), Err(StringStreamError::Eoi));
+    }
 }
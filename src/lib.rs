@@ -213,8 +213,8 @@ pub use crate::parser::{
     error::{unexpected, unexpected_any},
     function::parser,
     repeat::{
-        chainl1, chainr1, count, count_min_max, many, many1, sep_by, sep_by1, sep_end_by,
-        sep_end_by1, skip_count, skip_count_min_max, skip_many, skip_many1,
+        chainl1, chainr1, count, count_min_max, end_by, end_by1, many, many1, sep_by, sep_by1,
+        sep_end_by, sep_end_by1, skip_count, skip_count_min_max, skip_many, skip_many1,
     },
     sequence::between,
     token::{
@@ -634,6 +634,33 @@ mod tests {
         assert!(p.parse("abc,ab").is_err());
     }
 
+    #[test]
+    fn chain_associativity() {
+        fn number<Input>() -> impl Parser<Input, Output = i32>
+        where
+            Input: Stream<Token = char>,
+        {
+            crate::parser::char::digit().map(|c| c as i32 - '0' as i32)
+        }
+        fn sub<Input>() -> impl Parser<Input, Output = fn(i32, i32) -> i32>
+        where
+            Input: Stream<Token = char>,
+        {
+            char('-').map(|_| (|l, r| l - r) as fn(i32, i32) -> i32)
+        }
+
+        assert_eq!(chainl1(number(), sub()).parse("1-2-3"), Ok((-4, "")));
+        assert_eq!(chainr1(number(), sub()).parse("1-2-3"), Ok((2, "")));
+
+        // Stops before an operator which can't be parsed without committing
+        assert_eq!(chainl1(number(), sub()).parse("1-2-3)"), Ok((-4, ")")));
+        assert_eq!(chainr1(number(), sub()).parse("1-2-3)"), Ok((2, ")")));
+
+        // Fails if the operator is parsed but the next operand is not
+        assert!(chainl1(number(), sub()).parse("1-2-)").is_err());
+        assert!(chainr1(number(), sub()).parse("1-2-)").is_err());
+    }
+
     #[test]
     fn choice_strings() {
         let mut fruits = [
@@ -666,7 +693,12 @@ mod std_tests {
         },
     };
 
-    use super::{easy::Error, error::Commit, stream::IteratorStream, *};
+    use super::{
+        easy::Error,
+        error::Commit,
+        stream::{IteratorStream, ResetStream},
+        *,
+    };
 
     #[test]
     fn optional_error_consume() {
@@ -675,29 +707,6 @@ mod std_tests {
         assert_eq!(err.position, SourcePosition { line: 1, column: 1 });
     }
 
-    fn follow<Input>(input: &mut Input) -> StdParseResult<(), Input>
-    where
-        Input: Stream<Token = char, Error = easy::ParseError<Input>>,
-        Input::Position: Default,
-        Input::Error: std::fmt::Debug,
-        Input::Token: PartialEq,
-        Input::Range: PartialEq,
-    {
-        let before = input.checkpoint();
-        match input.uncons() {
-            Ok(c) => {
-                if c.is_alphanumeric() {
-                    input.reset(before).unwrap();
-                    let e = Error::Unexpected(c.into());
-                    Err(Commit::Peek(easy::Errors::new(input.position(), e).into()))
-                } else {
-                    Ok(((), Commit::Peek(())))
-                }
-            }
-            Err(_) => Ok(((), Commit::Peek(()))),
-        }
-    }
-
     fn integer<'a, Input>(input: &mut Input) -> StdParseResult<i64, Input>
     where
         Input: Stream<Token = char>,
@@ -719,6 +728,27 @@ mod std_tests {
         let result = parser(integer).parse("123");
         assert_eq!(result, Ok((123i64, "")));
     }
+
+    #[test]
+    fn and_then_error_position() {
+        let mut p = spaces().with(many1::<String, _, _>(digit()).and_then(|s| s.parse::<i64>()));
+        assert_eq!(
+            p.easy_parse(position::Stream::new("  42")).map(|t| t.0),
+            Ok(42)
+        );
+
+        // The conversion error is reported where the number starts, not where it ended
+        let err = p
+            .easy_parse(position::Stream::new("  99999999999999999999"))
+            .unwrap_err();
+        assert_eq!(err.position, SourcePosition { line: 1, column: 3 });
+        match err.errors[..] {
+            [Error::Other(ref err)] => {
+                assert_eq!(err.to_string(), "number too large to fit in target type")
+            }
+            ref errors => panic!("Unexpected errors: {:?}", errors),
+        }
+    }
     #[test]
     fn list() {
         let mut p = sep_by(parser(integer), char(','));
@@ -743,6 +773,24 @@ mod std_tests {
         assert_eq!(c_decl, Ok((("x".to_string(), "int".to_string()), "")));
     }
 
+    #[test]
+    fn field_missing_colon() {
+        let word = || many::<String, _, _>(alpha_num());
+        // The error is reported where the missing `:` should have been, not where the tuple started
+        let err = (word(), spaces(), char(':'), spaces(), word())
+            .easy_parse(position::Stream::new("x int"))
+            .unwrap_err();
+        assert_eq!(err.position, SourcePosition { line: 1, column: 3 });
+        assert_eq!(
+            err.errors,
+            vec![
+                Error::Unexpected('i'.into()),
+                Error::Expected("whitespace".into()),
+                Error::Expected(':'.into()),
+            ]
+        );
+    }
+
     #[test]
     fn source_position() {
         let source = r"
@@ -855,15 +903,40 @@ mod std_tests {
         assert_eq!(result, Expr::Plus(Box::new(e1), Box::new(e2)));
     }
 
+    #[test]
+    fn chainr1_missing_operand_error() {
+        let number = digit().map(|c: char| c.to_digit(10).unwrap());
+        let pow = char('^').map(|_| |l: u32, r: u32| l.pow(r));
+        assert_eq!(
+            chainr1(number, pow).easy_parse(position::Stream::new("2^)")),
+            Err(easy::Errors {
+                position: SourcePosition { line: 1, column: 3 },
+                errors: vec![Error::Unexpected(')'.into()), Error::Expected("digit".into())],
+            })
+        );
+    }
+
     #[test]
     fn error_position() {
+        type Input<'a> = easy::Stream<position::Stream<&'a str, SourcePosition>>;
+        let follow = parser(|input: &mut Input| {
+            let before = input.checkpoint();
+            match input.uncons() {
+                Ok(c) if c.is_alphanumeric() => {
+                    input.reset(before).unwrap();
+                    let e = Error::Unexpected(c.into());
+                    Err(Commit::Peek(easy::Errors::new(input.position(), e).into()))
+                }
+                _ => Ok(((), Commit::Peek(()))),
+            }
+        });
         let mut p = string("let")
-            .skip(parser(follow))
+            .skip(follow)
             .map(|x| x.to_string())
             .or(many1(digit()));
         match p.easy_parse(position::Stream::new("le123")) {
             Ok(_) => assert!(false),
-            Err(err) => assert_eq!(err.position, SourcePosition { line: 1, column: 1 }),
+            Err(err) => assert_eq!(err.position, SourcePosition { line: 1, column: 3 }),
         }
         match p.easy_parse(position::Stream::new("let1")) {
             Ok(_) => assert!(false),
@@ -875,7 +948,7 @@ mod std_tests {
     fn sep_by_error_consume() {
         let mut p = sep_by::<Vec<_>, _, _, _>(string("abc"), char(','));
         let err = p.easy_parse(position::Stream::new("ab,abc")).unwrap_err();
-        assert_eq!(err.position, SourcePosition { line: 1, column: 1 });
+        assert_eq!(err.position, SourcePosition { line: 1, column: 3 });
     }
 
     #[test]
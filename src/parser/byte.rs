@@ -1,14 +1,17 @@
 //! Module containing parsers specialized on byte streams.
 
 use crate::{
-    error::{self, ParseError, ParseResult::*},
+    error::{self, ParseError, ParseResult::*, StreamError},
+    lib::str,
     parser::{
+        choice::choice,
         combinator::no_partial,
-        range::{take_fn, TakeRange},
-        repeat::skip_many,
-        token::{satisfy, token, tokens_cmp, Token},
+        function::parser,
+        range::{take, take_fn, TakeRange},
+        repeat::{skip_count_min_max, skip_many},
+        token::{eof, satisfy, token, tokens_cmp, Token},
     },
-    stream::{RangeStream, Stream},
+    stream::{Range as StreamRange, RangeStream, Stream, StreamErrorFor},
     Parser,
 };
 
@@ -30,13 +33,13 @@ where
 }
 
 macro_rules! byte_parser {
-    ($name:ident, $ty:ident, $f: ident) => {{
+    ($expected: expr, $f: ident) => {{
         satisfy(|c: u8| c.$f())
-            .expected(stringify!($name))
+            .expected($expected)
     }};
-    ($name:ident, $ty:ident, $f: ident $($args:tt)+) => {{
+    ($expected: expr, $f: ident $($args:tt)+) => {{
         satisfy(|c: u8| c.$f $($args)+)
-            .expected(stringify!($name))
+            .expected($expected)
     }};
 }
 
@@ -53,7 +56,7 @@ where
     Input: Stream<Token = u8>,
     Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
 {
-    byte_parser!(digit, Digit, is_ascii_digit())
+    byte_parser!("digit", is_ascii_digit())
 }
 
 /// Parses a `b' '`, `b'\t'`, `b'\n'` or `'b\'r'`.
@@ -71,7 +74,7 @@ where
     Input: Stream<Token = u8>,
     Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
 {
-    byte_parser!(space, Space, is_ascii_whitespace)
+    byte_parser!("whitespace", is_ascii_whitespace)
 }
 
 /// Skips over [`space`] zero or more times
@@ -125,6 +128,26 @@ where
     no_partial(satisfy(|ch: u8| ch == b'\r').with(newline())).expected("crlf newline")
 }
 
+/// Parses the end of a line, which is either a newline (`b'\n'`), a carriage return followed by a
+/// newline (`&b"\r\n"`) or the end of the input.
+///
+/// ```
+/// use combine::Parser;
+/// use combine::parser::byte::end_of_line;
+/// assert_eq!(end_of_line().parse(&b"\nx"[..]), Ok(((), &b"x"[..])));
+/// assert_eq!(end_of_line().parse(&b"\r\nx"[..]), Ok(((), &b"x"[..])));
+/// assert_eq!(end_of_line().parse(&b""[..]), Ok(((), &b""[..])));
+/// assert!(end_of_line().parse(&b"\r"[..]).is_err());
+/// assert!(end_of_line().parse(&b"x"[..]).is_err());
+/// ```
+pub fn end_of_line<Input>() -> impl Parser<Input, Output = (), PartialState = ()>
+where
+    Input: Stream<Token = u8>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    no_partial(choice((newline().map(|_| ()), crlf().map(|_| ()), eof()))).expected("end of line")
+}
+
 /// Parses a tab byte (`b'\t'`).
 ///
 /// ```
@@ -154,7 +177,7 @@ where
     Input: Stream<Token = u8>,
     Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
 {
-    byte_parser!(upper, Upper, is_ascii_uppercase)
+    byte_parser!("uppercase letter", is_ascii_uppercase)
 }
 
 /// Parses an lowercase ASCII letter (a–z).
@@ -170,7 +193,7 @@ where
     Input: Stream<Token = u8>,
     Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
 {
-    byte_parser!(lower, Lower, is_ascii_lowercase)
+    byte_parser!("lowercase letter", is_ascii_lowercase)
 }
 
 /// Parses either an ASCII alphabet letter or digit (a–z, A–Z, 0–9).
@@ -187,7 +210,7 @@ where
     Input: Stream<Token = u8>,
     Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
 {
-    byte_parser!(alpha_num, AlphaNum, is_ascii_alphanumeric)
+    byte_parser!("letter or digit", is_ascii_alphanumeric)
 }
 
 /// Parses an ASCII alphabet letter (a–z, A–Z).
@@ -204,7 +227,7 @@ where
     Input: Stream<Token = u8>,
     Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
 {
-    byte_parser!(letter, Letter, is_ascii_alphabetic)
+    byte_parser!("letter", is_ascii_alphabetic)
 }
 
 /// Parses an octal digit.
@@ -236,7 +259,44 @@ where
     Input: Stream<Token = u8>,
     Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
 {
-    byte_parser!(hex_digit, HexDigit, is_ascii_hexdigit())
+    byte_parser!("hexadecimal digit", is_ascii_hexdigit())
+}
+
+/// Parses a binary digit.
+///
+/// ```
+/// use combine::Parser;
+/// use combine::parser::byte::bin_digit;
+/// assert_eq!(bin_digit().parse(&b"1"[..]), Ok((b'1', &b""[..])));
+/// assert!(bin_digit().parse(&b"2"[..]).is_err());
+/// ```
+pub fn bin_digit<Input>() -> impl Parser<Input, Output = u8, PartialState = ()>
+where
+    Input: Stream<Token = u8>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    satisfy(|ch| ch == b'0' || ch == b'1').expected("binary digit")
+}
+
+/// Parses an ASCII digit in the given `radix`, accepting both uppercase and lowercase letters for
+/// radixes above 10.
+///
+/// Panics if `radix` is not in the range `2..=36`.
+///
+/// ```
+/// use combine::Parser;
+/// use combine::parser::byte::digit_radix;
+/// assert_eq!(digit_radix(3).parse(&b"2"[..]), Ok((b'2', &b""[..])));
+/// assert_eq!(digit_radix(36).parse(&b"Z"[..]), Ok((b'Z', &b""[..])));
+/// assert!(digit_radix(3).parse(&b"3"[..]).is_err());
+/// ```
+pub fn digit_radix<Input>(radix: u32) -> impl Parser<Input, Output = u8, PartialState = ()>
+where
+    Input: Stream<Token = u8>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    let expected = crate::parser::char::radix_digit_label(radix);
+    satisfy(move |ch: u8| (ch as char).is_digit(radix)).expected(expected)
 }
 
 parser! {
@@ -425,6 +485,147 @@ where [
 
 }
 
+/// Zero-copy parser which reads exactly `n` bytes.
+///
+/// Equivalent to [`take`](../range/fn.take.html), so the error for a short input also reports how
+/// many bytes were available.
+///
+/// ```
+/// use combine::*;
+/// use combine::parser::byte::{num::be_u16, take_bytes};
+/// use combine::stream::easy;
+/// let mut chunk = be_u16().then(|len| take_bytes(usize::from(len)));
+/// assert_eq!(chunk.parse(&b"\x00\x02abc"[..]), Ok((&b"ab"[..], &b"c"[..])));
+/// assert!(take_bytes(4)
+///     .easy_parse(&b"ab"[..])
+///     .unwrap_err()
+///     .errors
+///     .contains(&easy::Error::Message("expected 4 elements but only 2 were available".to_string().into())));
+/// ```
+pub fn take_bytes<Input>(n: usize) -> impl Parser<Input, Output = Input::Range, PartialState = ()>
+where
+    Input: RangeStream<Token = u8>,
+    Input::Range: StreamRange,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    take(n)
+}
+
+/// Zero-copy parser which skips exactly `n` bytes.
+///
+/// ```
+/// use combine::Parser;
+/// use combine::parser::byte::{byte, skip_bytes};
+/// assert_eq!((skip_bytes(3), byte(b'!')).parse(&b"pad!"[..]), Ok((((), b'!'), &b""[..])));
+/// assert!(skip_bytes(3).parse(&b"pa"[..]).is_err());
+/// ```
+pub fn skip_bytes<Input>(n: usize) -> impl Parser<Input, Output = (), PartialState = ()>
+where
+    Input: RangeStream<Token = u8>,
+    Input::Range: StreamRange,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    take_bytes(n).map(|_| ())
+}
+
+/// Skips padding bytes until the absolute offset of the input is a multiple of `n`. Padding of a
+/// fixed size can be skipped with [`skip_bytes`], or with [`pad`] if it must be zero.
+///
+/// Only streams whose position is the offset from the start of the input, such as
+/// [`position::Stream`] over a byte slice, can be aligned.
+///
+/// Panics if `n` is zero.
+///
+/// [`skip_bytes`]: fn.skip_bytes.html
+/// [`pad`]: fn.pad.html
+/// [`position::Stream`]: ../../stream/position/struct.Stream.html
+///
+/// ```
+/// use combine::Parser;
+/// use combine::parser::byte::{align, num::be_u32};
+/// use combine::parser::token::any;
+/// use combine::stream::position;
+///
+/// // A tag byte followed by a 4 byte aligned value
+/// let mut field = (any(), align(4), be_u32()).map(|(tag, _, value)| (tag, value));
+/// let input = position::Stream::new(&b"T\0\0\0\0\0\0\x2A!"[..]);
+/// assert_eq!(field.parse(input).map(|(output, rest)| (output, rest.input)), Ok(((b'T', 42), &b"!"[..])));
+/// ```
+pub fn align<Input>(n: usize) -> impl Parser<Input, Output = (), PartialState = ()>
+where
+    Input: RangeStream<Token = u8, Position = usize>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+    Input::Range: StreamRange,
+{
+    assert!(n > 0, "alignment must be greater than zero");
+    parser(move |input: &mut Input| {
+        let padding = (n - input.position() % n) % n;
+        skip_bytes(padding).parse_stream(input).into_result()
+    })
+}
+
+/// Skips `n` bytes of padding, which must all be zero. Unlike [`skip_bytes`] a non-zero byte is
+/// reported at its position, which catches layouts that have drifted out of sync with the input.
+///
+/// [`skip_bytes`]: fn.skip_bytes.html
+///
+/// ```
+/// use combine::Parser;
+/// use combine::parser::byte::{byte, pad};
+/// // A tag byte padded to 4 bytes
+/// let mut field = (byte(b'T'), pad(3)).map(|(tag, _)| tag);
+/// assert_eq!(field.parse(&b"T\0\0\0!"[..]), Ok((b'T', &b"!"[..])));
+/// assert!(field.parse(&b"T\0\x01\0"[..]).is_err());
+/// assert!(field.parse(&b"T\0"[..]).is_err());
+/// ```
+pub fn pad<Input>(n: usize) -> impl Parser<Input, Output = ()>
+where
+    Input: Stream<Token = u8>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    skip_count_min_max(n, n, byte(0).expected("zero padding"))
+}
+
+/// Zero-copy parser which reads a NUL-terminated string, returning the bytes before the
+/// terminating `0x00`. The terminator is consumed but not included in the output.
+///
+/// ```
+/// use combine::Parser;
+/// use combine::parser::byte::null_terminated;
+/// assert_eq!(null_terminated().parse(&b"ustar\0\x30"[..]), Ok((&b"ustar"[..], &b"\x30"[..])));
+/// assert_eq!(null_terminated().parse(&b"\0"[..]), Ok((&b""[..], &b""[..])));
+/// assert!(null_terminated().parse(&b"ustar"[..]).is_err());
+/// ```
+pub fn null_terminated<Input>() -> impl Parser<Input, Output = Input::Range>
+where
+    Input: RangeStream<Token = u8>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+    Input::Range: AsRef<[u8]> + StreamRange,
+{
+    take_until_byte(0).skip(byte(0))
+}
+
+/// Zero-copy parser which reads a NUL-terminated string like [`null_terminated`] and validates
+/// that it is UTF-8. Invalid UTF-8 is reported at the start of the string.
+///
+/// [`null_terminated`]: fn.null_terminated.html
+///
+/// ```
+/// use combine::Parser;
+/// use combine::parser::byte::null_terminated_str;
+/// assert_eq!(null_terminated_str().parse(&b"h\xC3\xA9\0"[..]), Ok(("h\u{e9}", &b""[..])));
+/// assert!(null_terminated_str().parse(&b"\xFF\0"[..]).is_err());
+/// ```
+pub fn null_terminated_str<'a, Input>() -> impl Parser<Input, Output = &'a str>
+where
+    Input: RangeStream<Token = u8, Range = &'a [u8]>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    null_terminated().and_then(|bytes: &'a [u8]| {
+        str::from_utf8(bytes).map_err(|_| StreamErrorFor::<Input>::expected_static_message("utf-8"))
+    })
+}
+
 fn memslice(needle: &[u8], haystack: &[u8]) -> Option<usize> {
     let (&prefix, suffix) = match needle.split_first() {
         Some(x) => x,
@@ -442,7 +643,12 @@ fn memslice(needle: &[u8], haystack: &[u8]) -> Option<usize> {
 /// Parsers for decoding numbers in big-endian or little-endian order.
 pub mod num {
 
-    use crate::{error::ResultExt, lib::mem::size_of, parser::function::parser, stream::uncons};
+    use crate::{
+        error::{ParseResult, ResultExt, StreamError},
+        lib::mem::size_of,
+        parser::function::parser,
+        stream::uncons,
+    };
 
     use super::*;
 
@@ -574,7 +780,7 @@ pub mod num {
     );
 
     integer_parser!(
-        /// Reads a i32 out of the byte stream with the specified endianess
+        /// Reads a f32 out of the byte stream with the specified endianess
         ///
         /// ```
         /// use combine::Parser;
@@ -587,7 +793,7 @@ pub mod num {
         pub F32, f32, be_f32, le_f32, read_f32
     );
     integer_parser!(
-        /// Reads a i64 out of the byte stream with the specified endianess
+        /// Reads a f64 out of the byte stream with the specified endianess
         ///
         /// ```
         /// use combine::Parser;
@@ -600,6 +806,116 @@ pub mod num {
         pub F64, f64, be_f64, le_f64, read_f64
     );
 
+    fn leb128<Input>(input: &mut Input, signed: bool) -> ParseResult<u64, Input::Error>
+    where
+        Input: Stream<Token = u8>,
+        Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+    {
+        let position = input.position();
+        let mut value = 0u64;
+        let mut shift = 0;
+        loop {
+            let byte = ctry!(uncons(input)).0;
+            // The tenth byte holds the last bit of a 64 bit value and must end the number
+            if shift == 63 {
+                let fits = if signed {
+                    byte == 0 || byte == 0x7F
+                } else {
+                    byte <= 1
+                };
+                if !fits {
+                    return PeekErr(
+                        Input::Error::from_error(
+                            position,
+                            StreamError::message_static_message("varint overflows 64 bits"),
+                        )
+                        .into(),
+                    );
+                }
+            }
+            value |= u64::from(byte & 0x7F) << shift;
+            shift += 7;
+            if byte & 0x80 == 0 {
+                if signed && shift < 64 && byte & 0x40 != 0 {
+                    value |= !0 << shift;
+                }
+                return CommitOk(value);
+            }
+        }
+    }
+
+    /// Reads an unsigned LEB128 encoded integer, also known as a varint.
+    ///
+    /// Values which do not fit in a `u64` are reported as errors at the start of the number.
+    ///
+    /// ```
+    /// use combine::Parser;
+    /// use combine::parser::byte::num::uleb128;
+    ///
+    /// assert_eq!(uleb128().parse(&b"\x96\x01"[..]), Ok((150, &b""[..])));
+    /// assert_eq!(uleb128().parse(&b"\x7F\x01"[..]), Ok((127, &b"\x01"[..])));
+    /// assert!(uleb128().parse(&b"\x80"[..]).is_err());
+    /// ```
+    pub fn uleb128<Input>() -> impl Parser<Input, Output = u64, PartialState = ()>
+    where
+        Input: Stream<Token = u8>,
+        Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+    {
+        parser(|input: &mut Input| {
+            let checkpoint = input.checkpoint();
+            let result = leb128(input, false);
+            if result.is_err() {
+                input.reset(checkpoint).committed().into_result()?;
+            }
+            result.into_result()
+        })
+    }
+
+    /// Reads a signed LEB128 encoded integer, as used by WebAssembly and DWARF.
+    ///
+    /// Values which do not fit in an `i64` are reported as errors at the start of the number.
+    ///
+    /// ```
+    /// use combine::Parser;
+    /// use combine::parser::byte::num::sleb128;
+    ///
+    /// assert_eq!(sleb128().parse(&b"\x7F"[..]), Ok((-1, &b""[..])));
+    /// assert_eq!(sleb128().parse(&b"\xC0\xBB\x78"[..]), Ok((-123_456, &b""[..])));
+    /// assert_eq!(sleb128().parse(&b"\x3F"[..]), Ok((63, &b""[..])));
+    /// ```
+    pub fn sleb128<Input>() -> impl Parser<Input, Output = i64, PartialState = ()>
+    where
+        Input: Stream<Token = u8>,
+        Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+    {
+        parser(|input: &mut Input| {
+            let checkpoint = input.checkpoint();
+            let result = leb128(input, true);
+            if result.is_err() {
+                input.reset(checkpoint).committed().into_result()?;
+            }
+            result.map(|value| value as i64).into_result()
+        })
+    }
+
+    /// Reads a zigzag encoded signed varint, as used by protobuf's `sint32` and `sint64`.
+    ///
+    /// ```
+    /// use combine::Parser;
+    /// use combine::parser::byte::num::zigzag_varint;
+    ///
+    /// assert_eq!(zigzag_varint().parse(&b"\x03"[..]), Ok((-2, &b""[..])));
+    /// assert_eq!(zigzag_varint().parse(&b"\x04"[..]), Ok((2, &b""[..])));
+    /// assert_eq!(zigzag_varint().parse(&b"\xFE\x01"[..]), Ok((127, &b""[..])));
+    /// ```
+    pub fn zigzag_varint<Input>() -> impl Parser<Input, Output = i64, PartialState = ()>
+    where
+        Input: Stream<Token = u8>,
+        Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+    {
+        uleb128().map(|n| (n >> 1) as i64 ^ -((n & 1) as i64))
+    }
+
     #[cfg(test)]
     mod tests {
 
@@ -607,6 +923,80 @@ pub mod num {
 
         use super::*;
 
+        #[cfg(feature = "std")]
+        #[test]
+        fn mixed_endian_header() {
+            use crate::parser::EasyParser;
+
+            let mut buf = vec![0x12, 0x34];
+            buf.extend_from_slice(&7u32.to_le_bytes());
+            buf.extend_from_slice(&(-2i32).to_be_bytes());
+            buf.extend_from_slice(&u64::max_value().to_le_bytes());
+            buf.extend_from_slice(&0.5f64.to_be_bytes());
+
+            let mut header = (be_u16(), le_u32(), be_i32(), le_u64(), be_f64());
+            assert_eq!(
+                header
+                    .easy_parse(position::Stream::new(&buf[..]))
+                    .map(|(output, _)| output),
+                Ok((0x1234, 7, -2, u64::max_value(), 0.5))
+            );
+
+            assert!(header
+                .easy_parse(position::Stream::new(&buf[..buf.len() - 1]))
+                .is_err());
+        }
+
+        #[test]
+        fn leb128_limits() {
+            let unsigned_max = b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x01";
+            assert_eq!(
+                uleb128().parse(&unsigned_max[..]),
+                Ok((u64::max_value(), &b""[..]))
+            );
+            assert!(uleb128()
+                .parse(&b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x02"[..])
+                .is_err());
+            assert!(uleb128()
+                .parse(&b"\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x00"[..])
+                .is_err());
+
+            let min = b"\x80\x80\x80\x80\x80\x80\x80\x80\x80\x7F";
+            assert_eq!(sleb128().parse(&min[..]), Ok((i64::min_value(), &b""[..])));
+            let max = b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x00";
+            assert_eq!(sleb128().parse(&max[..]), Ok((i64::max_value(), &b""[..])));
+            assert!(sleb128()
+                .parse(&b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x01"[..])
+                .is_err());
+
+            assert_eq!(
+                zigzag_varint().parse(&unsigned_max[..]),
+                Ok((i64::min_value(), &b""[..]))
+            );
+        }
+
+        #[cfg(feature = "std")]
+        #[test]
+        fn leb128_overflow_is_reported_at_the_start() {
+            use crate::{
+                parser::{token::any, EasyParser},
+                stream::easy::{Error, Errors},
+            };
+
+            assert_eq!(
+                (any(), uleb128()).easy_parse(position::Stream::new(
+                    &b"!\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x7F"[..]
+                )),
+                Err(Errors {
+                    position: 1,
+                    errors: vec![
+                        Error::Message("varint overflows 64 bits".into()),
+                        Error::Unexpected(0xFF.into()),
+                    ],
+                })
+            );
+        }
+
         #[test]
         fn no_rangestream() {
             let buf = 123.45f64.to_le_bytes();
@@ -642,6 +1032,160 @@ pub mod num {
     }
 }
 
+/// Parsers for reading fields which are not aligned to byte boundaries.
+pub mod bits {
+
+    use crate::{
+        error::{Commit, StreamError},
+        parser::function::parser,
+        stream::uncons_range,
+    };
+
+    use super::*;
+
+    /// Reads bits from a byte slice, most significant bit first.
+    ///
+    /// Reading past the end of the slice yields zero bits and makes the enclosing [`bits`] parser
+    /// fail with an end of input error.
+    ///
+    /// [`bits`]: fn.bits.html
+    pub struct BitReader<'a> {
+        bytes: &'a [u8],
+        position: usize,
+    }
+
+    impl<'a> BitReader<'a> {
+        fn new(bytes: &'a [u8]) -> Self {
+            BitReader { bytes, position: 0 }
+        }
+
+        /// Reads a single bit.
+        pub fn take_bit(&mut self) -> bool {
+            let byte = self.bytes.get(self.position / 8).cloned().unwrap_or(0);
+            let bit = byte & (0x80 >> (self.position % 8)) != 0;
+            self.position += 1;
+            bit
+        }
+
+        /// Reads `count` bits and returns them as the low bits of an integer.
+        ///
+        /// Panics if `count` is greater than 64.
+        pub fn take_bits(&mut self, count: u32) -> u64 {
+            assert!(count <= 64, "can not take more than 64 bits at once");
+            (0..count).fold(0, |value, _| value << 1 | u64::from(self.take_bit()))
+        }
+
+        /// Skips the remaining bits of the current byte, if any.
+        pub fn align(&mut self) {
+            let partial = self.position % 8;
+            if partial > 0 {
+                self.position += 8 - partial;
+            }
+        }
+
+        /// Returns the number of bits read so far.
+        pub fn bits_read(&self) -> usize {
+            self.position
+        }
+    }
+
+    /// Parses bit-granular data with `f`, which reads the bits it needs from a [`BitReader`].
+    ///
+    /// Afterwards every byte that `f` read from is consumed, so a partially read final byte is
+    /// skipped and parsing continues at the next byte boundary. If `f` reads past the end of the
+    /// input the parser fails, without consuming anything, with an error at the first byte.
+    ///
+    /// [`BitReader`]: struct.BitReader.html
+    ///
+    /// ```
+    /// use combine::Parser;
+    /// use combine::parser::byte::{bits::bits, byte};
+    ///
+    /// // A 3 bit version followed by a 5 bit flags field, then a byte aligned tag
+    /// let mut header = (bits(|b| (b.take_bits(3), b.take_bits(5))), byte(b'!'));
+    /// assert_eq!(
+    ///     header.parse(&b"\x45!"[..]),
+    ///     Ok((((0b010, 0b00101), b'!'), &b""[..]))
+    /// );
+    ///
+    /// // The remaining bits of a partially read byte are skipped
+    /// let mut flag = bits(|b| b.take_bit());
+    /// assert_eq!(flag.parse(&b"\x80\x01"[..]), Ok((true, &b"\x01"[..])));
+    ///
+    /// assert!(bits(|b| b.take_bits(12)).parse(&b"\xFF"[..]).is_err());
+    /// ```
+    pub fn bits<Input, F, O>(mut f: F) -> impl Parser<Input, Output = O, PartialState = ()>
+    where
+        Input: RangeStream<Token = u8>,
+        Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+        Input::Range: AsRef<[u8]>,
+        F: FnMut(&mut BitReader<'_>) -> O,
+    {
+        parser(move |input: &mut Input| {
+            let range = input.range();
+            let available = range.as_ref().len() * 8;
+            let mut reader = BitReader::new(range.as_ref());
+            let output = f(&mut reader);
+            if reader.position > available {
+                let mut errors =
+                    Input::Error::from_error(input.position(), StreamError::end_of_input());
+                errors.add(StreamError::message_format(format_args!(
+                    "expected {} more bits",
+                    reader.position - available
+                )));
+                return Err(Commit::Peek(errors.into()));
+            }
+            reader.align();
+            let (_, committed) = uncons_range(input, reader.position / 8).into_result()?;
+            Ok((output, committed))
+        })
+    }
+
+    #[cfg(test)]
+    mod tests {
+
+        use crate::stream::position;
+
+        use super::*;
+
+        #[cfg(feature = "std")]
+        #[test]
+        fn bit_fields_across_bytes() {
+            use crate::{
+                parser::EasyParser,
+                stream::easy::{Error, Errors},
+            };
+
+            let mut packet = (
+                bits(|b| {
+                    let flag = b.take_bit();
+                    let length = b.take_bits(11);
+                    b.align();
+                    (flag, length, b.bits_read())
+                }),
+                bits(|b| b.take_bits(16)),
+            );
+            assert_eq!(
+                packet
+                    .easy_parse(position::Stream::new(&b"\xC0\x20\x12\x34"[..]))
+                    .map(|(output, rest)| (output, rest.input)),
+                Ok((((true, 0x402, 16), 0x1234), &b""[..]))
+            );
+            assert_eq!(
+                packet.easy_parse(position::Stream::new(&b"\xC0\x20\x12"[..])),
+                Err(Errors {
+                    position: 2,
+                    errors: vec![
+                        Error::end_of_input(),
+                        Error::Message("expected 8 more bits".to_string().into()),
+                        Error::Unexpected(0x12.into()),
+                    ],
+                })
+            );
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -649,6 +1193,170 @@ mod tests {
 
     use super::*;
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn byte_class_errors() {
+        use crate::{
+            parser::EasyParser,
+            stream::easy::{self, Error},
+        };
+
+        let mut parsers: Vec<(
+            Box<dyn Parser<easy::Stream<&[u8]>, Output = u8, PartialState = ()>>,
+            &str,
+        )> = vec![
+            (Box::new(digit()), "digit"),
+            (Box::new(space()), "whitespace"),
+            (Box::new(upper()), "uppercase letter"),
+            (Box::new(lower()), "lowercase letter"),
+            (Box::new(alpha_num()), "letter or digit"),
+            (Box::new(letter()), "letter"),
+            (Box::new(oct_digit()), "octal digit"),
+            (Box::new(hex_digit()), "hexadecimal digit"),
+            (Box::new(bin_digit()), "binary digit"),
+            (Box::new(digit_radix(8)), "octal digit"),
+            (Box::new(digit_radix(36)), "digit in radix 36"),
+        ];
+        for (parser, expected) in &mut parsers {
+            assert_eq!(
+                parser.easy_parse(&b"!"[..]).map_err(|err| err.errors),
+                Err(vec![
+                    Error::Unexpected(b'!'.into()),
+                    Error::Expected((*expected).into())
+                ]),
+            );
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn binary_signature_errors() {
+        use crate::{
+            parser::EasyParser,
+            stream::easy::{Error, Errors, Info},
+        };
+
+        let mut header = (bytes(b"\x89PNG"), byte(0x7F));
+        assert_eq!(
+            header
+                .easy_parse(position::Stream::new(&b"\x89PNG\x7F!"[..]))
+                .map(|(output, rest)| (output, rest.input)),
+            Ok(((&b"\x89PNG"[..], 0x7F), &b"!"[..]))
+        );
+        assert_eq!(
+            header.easy_parse(position::Stream::new(&b"\x89PNG\x00"[..])),
+            Err(Errors {
+                position: 4,
+                errors: vec![
+                    Error::Unexpected(0u8.into()),
+                    Error::Expected(0x7Fu8.into())
+                ],
+            })
+        );
+        assert_eq!(
+            header.easy_parse(position::Stream::new(&b"\x89PNX"[..])),
+            Err(Errors {
+                position: 3,
+                errors: vec![Error::Unexpected(b'X'.into())],
+            })
+        );
+        assert_eq!(
+            header.easy_parse(position::Stream::new(&b"PNG"[..])),
+            Err(Errors {
+                position: 0,
+                errors: vec![
+                    Error::Unexpected(b'P'.into()),
+                    Error::Expected(Info::Range(&b"\x89PNG"[..])),
+                ],
+            })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn null_terminated_str_errors() {
+        use crate::{
+            parser::EasyParser,
+            stream::easy::{Error, Errors},
+        };
+
+        let mut names = (null_terminated_str(), null_terminated_str());
+        assert_eq!(
+            names.easy_parse(position::Stream::new(&b"ok\0b\xFFd\0"[..])),
+            Err(Errors {
+                position: 3,
+                errors: vec![Error::Expected("utf-8".into())],
+            })
+        );
+        assert_eq!(
+            names.easy_parse(position::Stream::new(&b"ok\0end"[..])),
+            Err(Errors {
+                position: 6,
+                errors: vec![Error::end_of_input()],
+            })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn align_to_absolute_offsets() {
+        use crate::{
+            parser::{token::any, EasyParser},
+            stream::easy::{Error, Errors},
+        };
+
+        let mut aligned = (align(8), any(), align(8), any());
+        assert_eq!(
+            aligned
+                .easy_parse(position::Stream::new(&b"a\0\0\0\0\0\0\0b"[..]))
+                .map(|(output, rest)| (output, rest.input)),
+            Ok((((), b'a', (), b'b'), &b""[..]))
+        );
+        assert_eq!(
+            aligned.easy_parse(position::Stream::new(&b"a\0\0"[..])),
+            Err(Errors {
+                position: 1,
+                errors: vec![
+                    Error::end_of_input(),
+                    Error::Message(
+                        "expected 7 elements but only 2 were available"
+                            .to_string()
+                            .into()
+                    ),
+                    Error::Unexpected(0.into()),
+                ],
+            })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn pad_requires_zero_bytes() {
+        use crate::{
+            parser::{token::any, EasyParser},
+            stream::easy::{Error, Errors},
+        };
+
+        let mut padded = (any(), pad(3), any());
+        assert_eq!(
+            padded
+                .easy_parse(position::Stream::new(&b"a\0\0\0b"[..]))
+                .map(|(output, rest)| (output, rest.input)),
+            Ok(((b'a', (), b'b'), &b""[..]))
+        );
+        assert_eq!(
+            padded.easy_parse(position::Stream::new(&b"a\0\x01\0b"[..])),
+            Err(Errors {
+                position: 2,
+                errors: vec![
+                    Error::Message("expected 2 more elements".to_string().into()),
+                    Error::Unexpected(1.into()),
+                    Error::Expected("zero padding".into()),
+                ],
+            })
+        );
+    }
+
     #[test]
     fn memslice_basic() {
         let haystack = b"abc123";
@@ -1,11 +1,23 @@
 //! Module containing parsers specialized on character streams.
 
+#[cfg(feature = "std")]
+use crate::parser::{
+    choice::optional,
+    combinator::{from_str, look_ahead, recognize},
+    repeat::{count_min_max, many, skip_many1},
+    sequence::between,
+};
+#[cfg(feature = "std")]
+use crate::{error::StreamError, stream::StreamErrorFor};
 use crate::{
-    error::ParseError,
+    error::{Format, ParseError},
+    lib::{fmt, ops::RangeInclusive},
     parser::{
-        combinator::no_partial,
-        repeat::skip_many,
-        token::{satisfy, token, tokens_cmp, Token},
+        choice::choice,
+        combinator::{attempt, no_partial, not_followed_by},
+        error::Expected,
+        repeat::{skip_many, SkipMany},
+        token::{eof, satisfy, satisfy_map, token, tokens, Satisfy, Token},
     },
     stream::Stream,
     Parser,
@@ -69,24 +81,35 @@ where
     satisfy(f).expected("whitespace")
 }
 
-/// Skips over zero or more spaces according to [`std::char::is_whitespace`].
-///
-/// This includes space characters, tabs and newlines.
-///
-/// [`std::char::is_whitespace`]: https://doc.rust-lang.org/std/primitive.char.html#method.is_whitespace
-///
-/// ```
-/// use combine::Parser;
-/// use combine::parser::char::spaces;
-/// assert_eq!(spaces().parse(""), Ok(((), "")));
-/// assert_eq!(spaces().parse("   "), Ok(((), "")));
-/// ```
-pub fn spaces<Input>() -> impl Parser<Input, Output = ()>
-where
-    Input: Stream<Token = char>,
-    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
-{
-    skip_many(space()).expected("whitespaces")
+parser! {
+    #[derive(Copy, Clone)]
+    pub struct Spaces;
+    type PartialState = <SkipMany<Input, Expected<Satisfy<Input, fn(char) -> bool>, &'static str>> as Parser<Input>>::PartialState;
+    /// Skips over zero or more spaces according to [`std::char::is_whitespace`].
+    ///
+    /// This includes space characters, tabs and newlines.
+    ///
+    /// Unlike `many(space())` nothing is collected, so skipping whitespace does not allocate. Use
+    /// [`lex`] or [`Parser::skip_spaces`] to skip the whitespace following a token.
+    ///
+    /// [`std::char::is_whitespace`]: https://doc.rust-lang.org/std/primitive.char.html#method.is_whitespace
+    /// [`lex`]: fn.lex.html
+    /// [`Parser::skip_spaces`]: ../../trait.Parser.html#method.skip_spaces
+    ///
+    /// ```
+    /// use combine::Parser;
+    /// use combine::parser::char::{digit, spaces};
+    /// assert_eq!(spaces().parse(""), Ok(((), "")));
+    /// assert_eq!(spaces().parse("   "), Ok(((), "")));
+    /// assert_eq!(digit().skip(spaces()).parse("1 \n\t"), Ok(('1', "")));
+    /// ```
+    pub fn spaces[Input]()(Input) -> ()
+    where
+        [Input: Stream<Token = char>,]
+    {
+        let f: fn(char) -> bool = char::is_whitespace;
+        skip_many(satisfy(f).expected("whitespace")).expected("whitespaces")
+    }
 }
 
 /// Parses a newline character (`'\n'`).
@@ -122,6 +145,26 @@ where
     no_partial(satisfy(|ch: char| ch == '\r').with(newline())).expected("crlf newline")
 }
 
+/// Parses the end of a line, which is either a newline (`'\n'`), a carriage return followed by a
+/// newline (`"\r\n"`) or the end of the input.
+///
+/// ```
+/// use combine::Parser;
+/// use combine::parser::char::end_of_line;
+/// assert_eq!(end_of_line().parse("\nx"), Ok(((), "x")));
+/// assert_eq!(end_of_line().parse("\r\nx"), Ok(((), "x")));
+/// assert_eq!(end_of_line().parse(""), Ok(((), "")));
+/// assert!(end_of_line().parse("\r").is_err());
+/// assert!(end_of_line().parse("x").is_err());
+/// ```
+pub fn end_of_line<Input>() -> impl Parser<Input, Output = (), PartialState = ()>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    no_partial(choice((newline().map(|_| ()), crlf().map(|_| ()), eof()))).expected("end of line")
+}
+
 /// Parses a tab character (`'\t'`).
 ///
 /// ```
@@ -244,6 +287,145 @@ where
     satisfy(|ch: char| ch.is_digit(0x10)).expected("hexadecimal digit")
 }
 
+/// Parses a binary digit.
+///
+/// ```
+/// use combine::Parser;
+/// use combine::parser::char::bin_digit;
+/// assert_eq!(bin_digit().parse("1"), Ok(('1', "")));
+/// assert!(bin_digit().parse("2").is_err());
+/// ```
+pub fn bin_digit<Input>() -> impl Parser<Input, Output = char, PartialState = ()>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    satisfy(|ch: char| ch.is_digit(2)).expected("binary digit")
+}
+
+pub(crate) struct RadixDigitLabel(u32);
+
+impl fmt::Display for RadixDigitLabel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            2 => write!(f, "binary digit"),
+            8 => write!(f, "octal digit"),
+            10 => write!(f, "digit"),
+            16 => write!(f, "hexadecimal digit"),
+            radix => write!(f, "digit in radix {}", radix),
+        }
+    }
+}
+
+pub(crate) fn radix_digit_label(radix: u32) -> Format<RadixDigitLabel> {
+    assert!(
+        (2..=36).contains(&radix),
+        "radix must be in the range 2..=36"
+    );
+    Format(RadixDigitLabel(radix))
+}
+
+/// Parses a digit in the given `radix`, accepting both uppercase and lowercase letters for radixes
+/// above 10.
+///
+/// Panics if `radix` is not in the range `2..=36`.
+///
+/// ```
+/// use combine::Parser;
+/// use combine::parser::char::digit_radix;
+/// assert_eq!(digit_radix(3).parse("2"), Ok(('2', "")));
+/// assert_eq!(digit_radix(36).parse("z"), Ok(('z', "")));
+/// assert!(digit_radix(3).parse("3").is_err());
+/// ```
+pub fn digit_radix<Input>(radix: u32) -> impl Parser<Input, Output = char, PartialState = ()>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    let expected = radix_digit_label(radix);
+    satisfy(move |ch: char| ch.is_digit(radix)).expected(expected)
+}
+
+/// Parses a decimal digit and returns its numeric value.
+///
+/// ```
+/// use combine::Parser;
+/// use combine::parser::char::digit_value;
+/// use combine::parser::repeat::many1;
+/// let mut number = many1(digit_value()).map(|digits: Vec<u32>| {
+///     digits.into_iter().fold(0, |acc, d| acc * 10 + d)
+/// });
+/// assert_eq!(number.parse("123"), Ok((123, "")));
+/// assert!(digit_value().parse("a").is_err());
+/// ```
+pub fn digit_value<Input>() -> impl Parser<Input, Output = u32, PartialState = ()>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    digit_value_radix(10)
+}
+
+/// Parses a digit in the given `radix` and returns its numeric value. Like [`digit_radix`] both
+/// uppercase and lowercase letters are accepted for radixes above 10.
+///
+/// Panics if `radix` is not in the range `2..=36`.
+///
+/// [`digit_radix`]: fn.digit_radix.html
+///
+/// ```
+/// use combine::Parser;
+/// use combine::parser::char::digit_value_radix;
+/// assert_eq!(digit_value_radix(16).parse("f"), Ok((15, "")));
+/// assert_eq!(digit_value_radix(16).parse("B"), Ok((11, "")));
+/// assert!(digit_value_radix(8).parse("8").is_err());
+/// ```
+pub fn digit_value_radix<Input>(radix: u32) -> impl Parser<Input, Output = u32, PartialState = ()>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    let expected = radix_digit_label(radix);
+    satisfy_map(move |ch: char| ch.to_digit(radix)).expected(expected)
+}
+
+struct CharRangeLabel(char, char);
+
+impl fmt::Display for CharRangeLabel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "character in {:?}..={:?}", self.0, self.1)
+    }
+}
+
+/// Parses a character within the inclusive `range`.
+///
+/// Unlike `satisfy` the error names the range that was expected.
+///
+/// ```
+/// use combine::{EasyParser, Parser};
+/// use combine::parser::char::char_range;
+/// use combine::stream::easy;
+/// assert_eq!(char_range('a'..='z').parse("q"), Ok(('q', "")));
+/// assert_eq!(char_range('a'..='z').parse("z"), Ok(('z', "")));
+/// assert_eq!(
+///     char_range('a'..='z').easy_parse("Q").map_err(|err| err.errors),
+///     Err(vec![
+///         easy::Error::Unexpected('Q'.into()),
+///         easy::Error::Expected("character in 'a'..='z'".to_string().into()),
+///     ])
+/// );
+/// ```
+pub fn char_range<Input>(
+    range: RangeInclusive<char>,
+) -> impl Parser<Input, Output = char, PartialState = ()>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    let label = CharRangeLabel(*range.start(), *range.end());
+    satisfy(move |ch: char| range.contains(&ch)).expected(Format(label))
+}
+
 /// Parses the string `s`.
 ///
 /// ```
@@ -285,16 +467,490 @@ where
     Input: Stream<Token = char>,
     Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
 {
-    tokens_cmp(s.chars(), cmp).map(move |_| s).expected(s)
+    tokens(cmp, s, s.chars()).map(move |_| s)
+}
+
+/// Parses the string `s`, ignoring ASCII case differences.
+///
+/// ```
+/// use combine::Parser;
+/// use combine::parser::char::string_nocase;
+/// assert_eq!(string_nocase("Content-Length").parse("content-LENGTH"), Ok(("Content-Length", "")));
+/// ```
+pub fn string_nocase<Input>(s: &'static str) -> impl Parser<Input, Output = &'static str>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    string_cmp(s, |l, r| l.eq_ignore_ascii_case(&r))
+}
+
+/// Parses the keyword `word`, succeeding only if it is not immediately followed by a letter, digit
+/// or `_`. Nothing is consumed if the keyword does not match, so `keyword("let")` does not match the
+/// start of `letter`.
+///
+/// ```
+/// use combine::Parser;
+/// use combine::parser::char::keyword;
+/// assert_eq!(keyword("let").parse("let x"), Ok(("let", " x")));
+/// assert_eq!(keyword("let").parse("let"), Ok(("let", "")));
+/// assert!(keyword("let").parse("letter").is_err());
+/// ```
+pub fn keyword<Input>(word: &'static str) -> impl Parser<Input, Output = &'static str>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    attempt(string(word).skip(not_followed_by(satisfy(is_identifier_continue))))
+}
+
+/// Wraps `p` so that any whitespace following it is skipped.
+///
+/// Leading whitespace is not skipped so a grammar built from lexemes only needs to skip the
+/// whitespace at the very start of the input.
+///
+/// ```
+/// use combine::Parser;
+/// use combine::parser::char::{digit, lex, spaces};
+/// let mut parser = spaces().with((lex(digit()), lex(digit())));
+/// assert_eq!(parser.parse(" 1  2"), Ok((('1', '2'), "")));
+/// ```
+pub fn lex<Input, P>(p: P) -> impl Parser<Input, Output = P::Output>
+where
+    P: Parser<Input>,
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    lex_with(p, spaces())
+}
+
+/// Wraps `p` so that anything matched by `skip` is skipped after it, letting the "whitespace" of
+/// a language include things such as comments.
+///
+/// ```
+/// use combine::{Parser, skip_many, many, none_of};
+/// use combine::parser::char::{digit, lex_with, space, string};
+/// let comment = || string("//").with(skip_many(none_of("\n".chars())));
+/// let whitespace = || skip_many(space().map(|_| ()).or(comment()));
+/// let mut parser = many::<Vec<_>, _, _>(lex_with(digit(), whitespace()));
+/// assert_eq!(parser.parse("1 // one\n2"), Ok((vec!['1', '2'], "")));
+/// ```
+pub fn lex_with<Input, P, S>(p: P, skip: S) -> impl Parser<Input, Output = P::Output>
+where
+    P: Parser<Input>,
+    S: Parser<Input>,
+    Input: Stream,
+{
+    p.skip(skip)
+}
+
+/// Parses the string `s` and skips any whitespace following it. Equivalent to `lex(string(s))`.
+///
+/// ```
+/// use combine::Parser;
+/// use combine::parser::char::{letter, symbol};
+/// let mut parser = (symbol("let"), letter());
+/// assert_eq!(parser.parse("let  x"), Ok((("let", 'x'), "")));
+/// ```
+pub fn symbol<Input>(s: &'static str) -> impl Parser<Input, Output = &'static str>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    lex(string(s))
+}
+
+#[cfg(feature = "std")]
+fn sign<Input>() -> impl Parser<Input, Output = char, PartialState = ()>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    satisfy(|ch: char| ch == '-' || ch == '+')
+}
+
+/// Parses an optionally signed base-10 integer.
+///
+/// A sign which is not followed by a digit is not consumed and numbers which do not fit in an
+/// `i64` are reported as errors at the start of the number.
+///
+/// ```
+/// use combine::Parser;
+/// use combine::parser::char::integer;
+/// assert_eq!(integer().parse("-123 "), Ok((-123, " ")));
+/// assert_eq!(integer().parse("+7"), Ok((7, "")));
+/// assert!(integer().parse("-x").is_err());
+/// assert!(integer().parse("9223372036854775808").is_err());
+/// ```
+#[cfg(feature = "std")]
+pub fn integer<Input>() -> impl Parser<Input, Output = i64>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    from_str(recognize::<String, _, _>((
+        optional(attempt(sign().skip(look_ahead(digit())))),
+        skip_many1(digit()),
+    )))
+    .expected("integer")
+}
+
+/// Parses an optionally signed integer whose digits are in the given `radix`. Like [`integer`],
+/// numbers which do not fit in an `i64` are reported as errors at the start of the number.
+///
+/// Panics if `radix` is not in the range `2..=36`.
+///
+/// [`integer`]: fn.integer.html
+///
+/// ```
+/// use combine::Parser;
+/// use combine::parser::char::integer_radix;
+/// assert_eq!(integer_radix(16).parse("-ff "), Ok((-255, " ")));
+/// assert_eq!(integer_radix(2).parse("+1012"), Ok((5, "2")));
+/// assert!(integer_radix(8).parse("-8").is_err());
+/// assert!(integer_radix(16).parse("8000000000000000").is_err());
+/// ```
+#[cfg(feature = "std")]
+pub fn integer_radix<Input>(radix: u32) -> impl Parser<Input, Output = i64>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    recognize::<String, _, _>((
+        optional(attempt(sign().skip(look_ahead(digit_radix(radix))))),
+        skip_many1(digit_radix(radix)),
+    ))
+    .and_then(move |s| {
+        i64::from_str_radix(&s, radix).map_err(StreamErrorFor::<Input>::message_format)
+    })
+    .expected("integer")
+}
+
+#[cfg(feature = "std")]
+fn separated_digits<Input>(
+    radix: u32,
+    separator: Option<char>,
+    leading_separator: bool,
+) -> impl Parser<Input, Output = String>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    let is_separator = move |ch: char| Some(ch) == separator;
+    recognize::<String, _, _>((
+        skip_many(satisfy(move |ch: char| {
+            leading_separator && is_separator(ch)
+        })),
+        digit_radix(radix),
+        skip_many(satisfy(move |ch: char| {
+            ch.is_digit(radix) || is_separator(ch)
+        })),
+    ))
+    .map(move |s| s.chars().filter(|&ch| !is_separator(ch)).collect())
+}
+
+/// Parses an optionally signed integer literal as written in Rust or C-like languages.
+///
+/// The digits are decimal unless preceded by one of the prefixes `0x` (hexadecimal), `0o` (octal)
+/// or `0b` (binary), in either case. If `separator` is given, that character may appear anywhere
+/// after the first digit, and directly after a radix prefix, and is ignored. Like [`integer`],
+/// numbers which do not fit in an `i64` are reported as errors at the start of the number.
+///
+/// [`integer`]: fn.integer.html
+///
+/// ```
+/// use combine::Parser;
+/// use combine::parser::char::integer_literal;
+/// assert_eq!(integer_literal(Some('_')).parse("1_000_000"), Ok((1_000_000, "")));
+/// assert_eq!(integer_literal(Some('_')).parse("-0xff_ff"), Ok((-0xffff, "")));
+/// assert_eq!(integer_literal(Some('\'')).parse("0b1010'0101"), Ok((0b1010_0101, "")));
+/// assert_eq!(integer_literal(None).parse("0o17_"), Ok((0o17, "_")));
+/// assert_eq!(integer_literal(None).parse("0"), Ok((0, "")));
+/// assert!(integer_literal(Some('_')).parse("_1").is_err());
+/// assert!(integer_literal(Some('_')).parse("0x_").is_err());
+/// ```
+#[cfg(feature = "std")]
+pub fn integer_literal<Input>(separator: Option<char>) -> impl Parser<Input, Output = i64>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    let radix_prefix = attempt(char('0').with(satisfy_map(
+        |ch: char| match ch.to_ascii_lowercase() {
+            'x' => Some(16),
+            'o' => Some(8),
+            'b' => Some(2),
+            _ => None,
+        },
+    )));
+    (
+        optional(attempt(sign().skip(look_ahead(digit())))),
+        optional(radix_prefix).then(move |radix| {
+            let digits = separated_digits(radix.unwrap_or(10), separator, radix.is_some());
+            digits.map(move |digits| (radix.unwrap_or(10), digits))
+        }),
+    )
+        .and_then(|(sign, (radix, digits))| {
+            let mut s: String = sign.into_iter().collect();
+            s.push_str(&digits);
+            i64::from_str_radix(&s, radix).map_err(StreamErrorFor::<Input>::message_format)
+        })
+        .expected("integer")
+}
+
+/// Parses an optionally signed floating point number with an optional fraction and exponent.
+///
+/// The integral part may be omitted (`.5`) but a `.` must always be followed by at least one digit.
+/// Neither a lone sign nor a lone `.` is consumed.
+///
+/// ```
+/// use combine::Parser;
+/// use combine::parser::char::float;
+/// assert_eq!(float().parse("-12.5"), Ok((-12.5, "")));
+/// assert_eq!(float().parse("3.14E-2"), Ok((0.0314, "")));
+/// assert_eq!(float().parse("1e10"), Ok((1e10, "")));
+/// assert_eq!(float().parse(".5"), Ok((0.5, "")));
+/// assert_eq!(float().parse("1.x"), Ok((1.0, ".x")));
+/// assert!(float().parse("-.").is_err());
+/// ```
+#[cfg(feature = "std")]
+pub fn float<Input>() -> impl Parser<Input, Output = f64>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    let dot = || satisfy(|ch: char| ch == '.');
+    let mantissa_start = || choice((digit(), attempt(dot().skip(look_ahead(digit())))));
+    let fraction = || (attempt((dot(), digit())), skip_many(digit()));
+    let mantissa = choice((
+        (skip_many1(digit()), optional(fraction())).map(|_| ()),
+        fraction().map(|_| ()),
+    ));
+    let exponent = (
+        attempt((
+            satisfy(|ch: char| ch == 'e' || ch == 'E'),
+            optional(sign()),
+            digit(),
+        )),
+        skip_many(digit()),
+    );
+    from_str(recognize::<String, _, _>((
+        optional(attempt(sign().skip(look_ahead(mantissa_start())))),
+        mantissa.expected("float"),
+        optional(exponent),
+    )))
+    .expected("float")
+}
+
+/// Parses a floating point number like [`float`] but also accepts the special values `inf`,
+/// `infinity` and `nan` in any case and with an optional sign.
+///
+/// [`float`]: fn.float.html
+///
+/// ```
+/// use combine::Parser;
+/// use combine::parser::char::float_with_special;
+/// assert_eq!(float_with_special().parse("-12.5"), Ok((-12.5, "")));
+/// assert_eq!(float_with_special().parse("-inf"), Ok((std::f64::NEG_INFINITY, "")));
+/// assert_eq!(float_with_special().parse("Infinity"), Ok((std::f64::INFINITY, "")));
+/// assert!(float_with_special().parse("NaN").unwrap().0.is_nan());
+/// assert!(float_with_special().parse("info").is_err());
+/// ```
+#[cfg(feature = "std")]
+pub fn float_with_special<Input>() -> impl Parser<Input, Output = f64>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    let word = attempt(
+        choice((
+            attempt(string_nocase("infinity")).map(|_| f64::INFINITY),
+            attempt(string_nocase("inf")).map(|_| f64::INFINITY),
+            attempt(string_nocase("nan")).map(|_| f64::NAN),
+        ))
+        .skip(not_followed_by(satisfy(is_identifier_continue))),
+    );
+    let word_start = || satisfy(|ch: char| ch == 'i' || ch == 'I' || ch == 'n' || ch == 'N');
+    let special = (
+        optional(attempt(sign().skip(look_ahead(word_start())))),
+        word.silent(),
+    )
+        .map(|(sign, value)| if sign == Some('-') { -value } else { value });
+    choice((float(), special)).expected("float")
+}
+
+#[cfg(feature = "std")]
+fn is_identifier_start(ch: char) -> bool {
+    if ch.is_ascii() {
+        ch.is_ascii_alphabetic() || ch == '_'
+    } else {
+        ch.is_alphabetic()
+    }
+}
+
+fn is_identifier_continue(ch: char) -> bool {
+    if ch.is_ascii() {
+        ch.is_ascii_alphanumeric() || ch == '_'
+    } else {
+        ch.is_alphanumeric()
+    }
+}
+
+/// Parses an identifier, a letter or `_` followed by any number of letters, digits or `_`, and
+/// returns the matched text.
+///
+/// Outside of ASCII, letters and digits are decided by [`char::is_alphabetic`] and
+/// [`char::is_alphanumeric`] which approximate the Unicode `XID_Start` and `XID_Continue`
+/// properties (combining marks are not accepted in identifiers).
+///
+/// [`char::is_alphabetic`]: https://doc.rust-lang.org/std/primitive.char.html#method.is_alphabetic
+/// [`char::is_alphanumeric`]: https://doc.rust-lang.org/std/primitive.char.html#method.is_alphanumeric
+///
+/// ```
+/// use combine::Parser;
+/// use combine::parser::char::identifier;
+/// assert_eq!(identifier().parse("_foo1 = 2"), Ok(("_foo1".to_string(), " = 2")));
+/// assert_eq!(identifier().parse("größe"), Ok(("größe".to_string(), "")));
+/// assert!(identifier().parse("1x").is_err());
+/// ```
+#[cfg(feature = "std")]
+pub fn identifier<Input>() -> impl Parser<Input, Output = String>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    recognize((
+        satisfy(is_identifier_start),
+        skip_many(satisfy(is_identifier_continue)),
+    ))
+    .expected("identifier")
+}
+
+#[cfg(feature = "std")]
+fn escape_sequence<Input>() -> impl Parser<Input, Output = char>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    let simple = satisfy_map(|ch| match ch {
+        'n' => Some('\n'),
+        't' => Some('\t'),
+        'r' => Some('\r'),
+        '0' => Some('\0'),
+        '\\' | '\'' | '"' => Some(ch),
+        _ => None,
+    });
+    let byte = char('x')
+        .with(count_min_max::<String, _, _>(2, 2, hex_digit()))
+        .and_then(|digits| match u8::from_str_radix(&digits, 16).unwrap() {
+            n @ 0..=0x7F => Ok(char::from(n)),
+            _ => Err(StreamErrorFor::<Input>::message_static_message(
+                "out of range hex escape",
+            )),
+        });
+    let unicode = char('u')
+        .with(between(
+            char('{'),
+            char('}'),
+            count_min_max::<String, _, _>(1, 6, hex_digit()),
+        ))
+        .and_then(|digits| {
+            u32::from_str_radix(&digits, 16)
+                .ok()
+                .and_then(std::char::from_u32)
+                .ok_or_else(|| {
+                    StreamErrorFor::<Input>::message_static_message("invalid unicode escape")
+                })
+        });
+    char('\\').with(choice((simple, byte, unicode)).expected("escape sequence"))
+}
+
+/// Parses a string literal delimited by `quote` and returns its decoded contents.
+///
+/// A backslash starts an escape sequence, which is one of `\n`, `\t`, `\r`, `\0`, `\\`, `\'`,
+/// `\"`, `\xNN` (two hexadecimal digits naming an ASCII character, so at most `\x7F`) or `\u{N}`
+/// (one to six hexadecimal digits naming a unicode scalar value). Invalid escapes are reported at
+/// the character following the backslash.
+///
+/// ```
+/// use combine::Parser;
+/// use combine::parser::char::string_literal;
+/// assert_eq!(
+///     string_literal('"').parse(r#""a\tb\x41\u{e9}\"" rest"#),
+///     Ok(("a\tbA\u{e9}\"".to_string(), " rest"))
+/// );
+/// assert_eq!(string_literal('\'').parse("'it\\'s'"), Ok(("it's".to_string(), "")));
+/// assert!(string_literal('"').parse(r#""\q""#).is_err());
+/// assert!(string_literal('"').parse(r#""unterminated"#).is_err());
+/// ```
+#[cfg(feature = "std")]
+pub fn string_literal<Input>(quote: char) -> impl Parser<Input, Output = String>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    between(
+        char(quote),
+        char(quote),
+        many(choice((
+            escape_sequence(),
+            satisfy(move |ch: char| ch != quote && ch != '\\'),
+        ))),
+    )
+}
+
+/// Parses a region delimited by `open` and `close` and returns the raw text between the
+/// delimiters.
+///
+/// Inside the region `escape` followed by any character is taken verbatim, so an escaped `close`
+/// does not end the region. Passing the closing character as `escape` gives the CSV convention
+/// where a doubled quote stands for a literal one. Escapes are kept as they appear in the input;
+/// use [`string_literal`] to decode them instead.
+///
+/// [`string_literal`]: fn.string_literal.html
+///
+/// ```
+/// use combine::Parser;
+/// use combine::parser::char::quoted;
+/// assert_eq!(
+///     quoted('"', '"', '"').parse(r#""a ""quoted"" field",x"#),
+///     Ok((r#"a ""quoted"" field"#.to_string(), ",x"))
+/// );
+/// assert_eq!(
+///     quoted('\'', '\'', '\\').parse(r"'it\'s' rest"),
+///     Ok((r"it\'s".to_string(), " rest"))
+/// );
+/// assert_eq!(quoted('(', ')', '\\').parse("(a (b)"), Ok(("a (b".to_string(), "")));
+/// assert!(quoted('"', '"', '"').parse(r#""unterminated"#).is_err());
+/// ```
+#[cfg(feature = "std")]
+pub fn quoted<Input>(open: char, close: char, escape: char) -> impl Parser<Input, Output = String>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    between(
+        char(open),
+        char(close),
+        recognize(skip_many(choice((
+            // When `escape` is also the closing character it only escapes another `close`
+            attempt((
+                char(escape),
+                satisfy(move |ch: char| escape != close || ch == close),
+            ))
+            .map(|_| ()),
+            satisfy(move |ch: char| ch != close && ch != escape).map(|_| ()),
+        )))),
+    )
 }
 
 #[cfg(all(feature = "std", test))]
 mod tests {
 
     use crate::{
-        parser::EasyParser,
+        parser::{repeat::skip_many1, EasyParser},
         stream::{
-            easy::{Error, Errors},
+            easy::{self, Error, Errors},
             position::{self, SourcePosition},
         },
     };
@@ -311,6 +967,421 @@ mod tests {
         );
     }
 
+    #[test]
+    fn skip_spaces() {
+        assert_eq!(spaces().with(digit()).parse("   5"), Ok(('5', "")));
+        assert_eq!(
+            skip_many1(space()).easy_parse(position::Stream::new("x")),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 1 },
+                errors: vec![
+                    Error::Unexpected('x'.into()),
+                    Error::Expected("whitespace".into())
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn char_class_errors() {
+        assert_eq!(
+            (char('x'), hex_digit()).easy_parse(position::Stream::new("xg")),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 2 },
+                errors: vec![
+                    Error::Unexpected('g'.into()),
+                    Error::Expected("hexadecimal digit".into())
+                ],
+            })
+        );
+        let mut parsers: Vec<(
+            Box<dyn Parser<easy::Stream<&str>, Output = char, PartialState = ()>>,
+            &str,
+        )> = vec![
+            (Box::new(letter()), "letter"),
+            (Box::new(alpha_num()), "letter or digit"),
+            (Box::new(oct_digit()), "octal digit"),
+            (Box::new(upper()), "uppercase letter"),
+            (Box::new(lower()), "lowercase letter"),
+            (Box::new(newline()), "lf newline"),
+            (Box::new(tab()), "tab"),
+            (Box::new(bin_digit()), "binary digit"),
+            (Box::new(digit_radix(16)), "hexadecimal digit"),
+            (Box::new(digit_radix(5)), "digit in radix 5"),
+        ];
+        for (parser, expected) in &mut parsers {
+            assert_eq!(
+                parser.easy_parse("!").map_err(|err| err.errors),
+                Err(vec![
+                    Error::Unexpected('!'.into()),
+                    Error::Expected((*expected).into())
+                ]),
+            );
+        }
+    }
+
+    #[test]
+    fn digit_value_errors() {
+        assert_eq!(
+            (digit_value(), digit_value_radix(2)).easy_parse(position::Stream::new("72")),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 2 },
+                errors: vec![
+                    Error::Unexpected('2'.into()),
+                    Error::Expected("binary digit".into())
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn char_reports_expected_character() {
+        let err = char('[')
+            .easy_parse(position::Stream::new("("))
+            .unwrap_err();
+        assert_eq!(
+            err,
+            Errors {
+                position: SourcePosition { line: 1, column: 1 },
+                errors: vec![Error::Unexpected('('.into()), Error::Expected('['.into())],
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "Parse error at line: 1, column: 1\nUnexpected `(`\nExpected `[`\n"
+        );
+    }
+
+    #[test]
+    fn end_of_line_accepts_both_conventions() {
+        let mut line = (skip_many1(letter()), end_of_line());
+        let mut input = position::Stream::new("ab\r\ncd\nef");
+        for expected in &[
+            SourcePosition { line: 2, column: 1 },
+            SourcePosition { line: 3, column: 1 },
+            SourcePosition { line: 3, column: 3 },
+        ] {
+            let (_, rest) = line.easy_parse(input).unwrap();
+            assert_eq!(rest.positioner, *expected);
+            input = rest;
+        }
+
+        assert_eq!(
+            line.easy_parse(position::Stream::new("ab\rcd")),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 4 },
+                errors: vec![
+                    Error::Unexpected('c'.into()),
+                    Error::Expected("lf newline".into()),
+                ],
+            })
+        );
+        assert_eq!(
+            line.easy_parse(position::Stream::new("ab cd")),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 3 },
+                errors: vec![
+                    Error::Unexpected(' '.into()),
+                    Error::Expected("letter".into()),
+                    Error::Expected("end of line".into()),
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn identifier_errors() {
+        assert_eq!(
+            (identifier(), char('=')).easy_parse(position::Stream::new("x1 =")),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 3 },
+                errors: vec![Error::Unexpected(' '.into()), Error::Expected('='.into())],
+            })
+        );
+        assert_eq!(
+            identifier().easy_parse(position::Stream::new("9lives")),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 1 },
+                errors: vec![
+                    Error::Unexpected('9'.into()),
+                    Error::Expected("identifier".into())
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn keyword_requires_a_word_boundary() {
+        let mut statement = choice((
+            keyword("let").map(|_| "let"),
+            identifier().map(|_| "identifier"),
+        ));
+        assert_eq!(statement.parse("let x"), Ok(("let", " x")));
+        assert_eq!(statement.parse("letter"), Ok(("identifier", "")));
+        assert_eq!(statement.parse("let_"), Ok(("identifier", "")));
+        assert_eq!(
+            keyword("let").easy_parse(position::Stream::new("let2")),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 4 },
+                errors: vec![Error::Unexpected('2'.into())],
+            })
+        );
+    }
+
+    #[test]
+    fn float_special_values() {
+        assert_eq!(float_with_special().parse("1e3 "), Ok((1e3, " ")));
+        assert_eq!(
+            float_with_special().parse("+INF,"),
+            Ok((f64::INFINITY, ","))
+        );
+        assert!(float().parse("inf").is_err());
+        assert_eq!(
+            float_with_special().easy_parse(position::Stream::new("-x")),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 1 },
+                errors: vec![
+                    Error::Unexpected('-'.into()),
+                    Error::Expected("float".into())
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn integer_radix_overflow_is_reported_at_the_start() {
+        assert_eq!(
+            (char('#'), integer_radix(16)).easy_parse(position::Stream::new("#-8000000000000001")),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 2 },
+                errors: vec![Error::Message(
+                    "number too small to fit in target type".to_string().into()
+                )],
+            })
+        );
+        assert_eq!(
+            integer_radix(16).parse("-8000000000000000"),
+            Ok((std::i64::MIN, ""))
+        );
+    }
+
+    #[test]
+    fn integer_literal_errors() {
+        assert_eq!(
+            integer_literal(Some('_')).easy_parse(position::Stream::new("0x_g")),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 4 },
+                errors: vec![
+                    Error::Unexpected('g'.into()),
+                    Error::Expected("hexadecimal digit".into())
+                ],
+            })
+        );
+        assert_eq!(
+            integer_literal(None)
+                .easy_parse(position::Stream::new("-0b1_0"))
+                .map(|(value, rest)| (value, rest.input)),
+            Ok((-1, "_0"))
+        );
+        assert_eq!(
+            (char('#'), integer_literal(Some('_')))
+                .easy_parse(position::Stream::new("#0x8000_0000_0000_0000")),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 2 },
+                errors: vec![Error::Message(
+                    "number too large to fit in target type".to_string().into()
+                )],
+            })
+        );
+    }
+
+    #[test]
+    fn string_literal_escape_errors() {
+        assert_eq!(
+            string_literal('"').easy_parse(position::Stream::new(r#""ab\q""#)),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 5 },
+                errors: vec![
+                    Error::Unexpected('q'.into()),
+                    Error::Expected("escape sequence".into())
+                ],
+            })
+        );
+        assert_eq!(
+            string_literal('"').easy_parse(position::Stream::new(r#""\x4""#)),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 5 },
+                errors: vec![
+                    Error::Message("expected 1 more elements".into()),
+                    Error::Unexpected('"'.into()),
+                    Error::Expected("hexadecimal digit".into()),
+                ],
+            })
+        );
+        assert_eq!(
+            string_literal('"')
+                .easy_parse(position::Stream::new(r#""\u{d800}""#))
+                .map_err(|err| err.errors),
+            Err(vec![Error::Message("invalid unicode escape".into())])
+        );
+        assert_eq!(
+            string_literal('"').easy_parse(position::Stream::new(r#""\x7f\xe9""#)),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 7 },
+                errors: vec![Error::Message("out of range hex escape".into())],
+            })
+        );
+        assert_eq!(
+            string_literal('"')
+                .easy_parse(position::Stream::new("\"a\nb\""))
+                .map(|(s, rest)| (s, rest.positioner)),
+            Ok(("a\nb".to_string(), SourcePosition { line: 2, column: 3 }))
+        );
+    }
+
+    #[test]
+    fn quoted_keeps_escapes_verbatim() {
+        assert_eq!(
+            quoted('"', '"', '"').easy_parse(position::Stream::new(r#""""""#)),
+            Ok((
+                "\"\"".to_string(),
+                position::Stream::with_positioner("", SourcePosition { line: 1, column: 5 })
+            ))
+        );
+        assert_eq!(
+            quoted('\'', '\'', '\\').easy_parse(position::Stream::new("'a\\")),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 3 },
+                errors: vec![Error::Unexpected('\\'.into()), Error::Expected('\''.into()),],
+            })
+        );
+    }
+
+    #[test]
+    fn char_range_error() {
+        assert_eq!(
+            char_range('0'..='7').easy_parse(position::Stream::new("8")),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 1 },
+                errors: vec![
+                    Error::Unexpected('8'.into()),
+                    Error::Expected("character in '0'..='7'".to_string().into()),
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn lexemes() {
+        let mut parser = (symbol("let"), lex(letter()), symbol("="), lex(digit()));
+        assert_eq!(parser.parse("let x = 1"), Ok((("let", 'x', "=", '1'), "")));
+        assert_eq!(parser.parse("let x=1 "), Ok((("let", 'x', "=", '1'), "")));
+        let mut parser = (symbol("let"), lex(letter()), symbol("="), lex(digit()));
+        assert_eq!(
+            parser.easy_parse(position::Stream::new("let x 1")),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 7 },
+                errors: vec![
+                    Error::Unexpected('1'.into()),
+                    Error::Expected("whitespace".into()),
+                    Error::Expected("=".into()),
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn symbol_output_outlives_the_input() {
+        let word: &'static str = {
+            let input = String::from("let x");
+            let (word, _) = symbol("let").parse(&input[..]).unwrap();
+            word
+        };
+        assert_eq!(word, "let");
+    }
+
+    #[test]
+    fn lex_with_byte_stream() {
+        use crate::parser::byte;
+        let mut parser = lex_with(byte::digit(), skip_many1(byte::space()));
+        assert_eq!(parser.parse(&b"1 \t2"[..]), Ok((b'1', &b"2"[..])));
+    }
+
+    #[test]
+    fn integer_literals() {
+        for &(s, n) in &[
+            ("0", 0),
+            ("-0", 0),
+            ("+42", 42),
+            ("-9223372036854775808", i64::min_value()),
+            ("9223372036854775807", i64::max_value()),
+        ] {
+            assert_eq!(integer().parse(s), Ok((n, "")), "{}", s);
+        }
+        assert_eq!(integer().parse("12a"), Ok((12, "a")));
+    }
+
+    #[test]
+    fn integer_error() {
+        assert_eq!(
+            integer().easy_parse(position::Stream::new("-x")),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 1 },
+                errors: vec![
+                    Error::Unexpected('-'.into()),
+                    Error::Expected("integer".into())
+                ],
+            })
+        );
+        // A lone sign is left for the next parser
+        assert_eq!(
+            integer().or(char('-').map(|_| -1)).parse("-x"),
+            Ok((-1, "x"))
+        );
+
+        let err = integer()
+            .easy_parse(position::Stream::new("9223372036854775808"))
+            .unwrap_err();
+        assert_eq!(err.position, SourcePosition { line: 1, column: 1 });
+    }
+
+    #[test]
+    fn float_literals() {
+        for &(s, n) in &[
+            ("0", 0.0),
+            ("-0", -0.0),
+            ("12", 12.0),
+            ("-12.5", -12.5),
+            ("+1.25", 1.25),
+            ("1e10", 1e10),
+            ("3.14E-2", 3.14e-2),
+            (".5", 0.5),
+            ("-.5e+1", -5.0),
+        ] {
+            assert_eq!(float().parse(s), Ok((n, "")), "{}", s);
+        }
+        assert_eq!(float().parse("1.x"), Ok((1.0, ".x")));
+        assert_eq!(float().parse("2e"), Ok((2.0, "e")));
+    }
+
+    #[test]
+    fn float_error() {
+        assert_eq!(
+            float().easy_parse(position::Stream::new("-x")),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 1 },
+                errors: vec![
+                    Error::Unexpected('-'.into()),
+                    Error::Expected("float".into())
+                ],
+            })
+        );
+        assert_eq!(
+            float().or(char('.').map(|_| -1.0)).parse(".x"),
+            Ok((-1.0, "x"))
+        );
+    }
+
     #[test]
     fn string_committed() {
         let result = string("a").easy_parse(position::Stream::new("b"));
@@ -321,6 +1392,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn string_error_at_mismatch() {
+        assert_eq!(
+            string("abc").easy_parse(position::Stream::new("abXc")),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 3 },
+                errors: vec![Error::Unexpected('X'.into()), Error::Expected("abc".into())],
+            })
+        );
+        assert_eq!(
+            string_nocase("CONTENT-length")
+                .easy_parse(position::Stream::new("content-LENGTH:"))
+                .map(|(s, rest)| (s, rest.input)),
+            Ok(("CONTENT-length", ":"))
+        );
+    }
+
+    #[test]
+    fn caseless_strings_report_the_canonical_spelling() {
+        assert_eq!(
+            string_nocase("SELECT").easy_parse(position::Stream::new("selct")),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 4 },
+                errors: vec![
+                    Error::Unexpected('c'.into()),
+                    Error::Expected("SELECT".into())
+                ],
+            })
+        );
+
+        let mut unicode = string_cmp("Äpfel", |l, r| l.to_lowercase().eq(r.to_lowercase()));
+        assert_eq!(unicode.parse("äPFEL"), Ok(("Äpfel", "")));
+        assert!(unicode.parse("apfel").is_err());
+    }
+
     #[test]
     fn string_error() {
         let result = string("abc").easy_parse(position::Stream::new("bc"));
@@ -15,6 +15,15 @@ use crate::{
 /// Fails if all the parsers fails or if an applied parser fails after it has committed to its
 /// parse.
 ///
+/// The parsers may have different types as long as their outputs are the same. `choice!(p1, p2,
+/// p3)` expands to [`choice((p1, p2, p3))`][fn choice] so the alternatives are tried in a flat
+/// sequence and the errors of the ones which failed are merged. As tuples only implement
+/// `ChoiceParser` for up to 25 parsers, longer lists nest the remaining alternatives in the last
+/// element of the tuple.
+///
+/// NOTE: The resulting parser is a `Choice` rather than the nested `Or` parsers this macro
+/// expanded to before, so code naming the type of the parser needs to be updated.
+///
 /// ```
 /// # #[macro_use]
 /// # extern crate combine;
@@ -31,13 +40,27 @@ use crate::{
 /// assert!(parser.parse(":123").is_err());
 /// # }
 /// ```
+///
+/// [fn choice]: fn.choice.html
 #[macro_export]
 macro_rules! choice {
-    ($first : expr) => {
+    ($first : expr $(,)?) => {
         $first
     };
-    ($first : expr, $($rest : expr),+) => {
-        $first.or(choice!($($rest),+))
+    (
+        $p1 : expr, $p2 : expr, $p3 : expr, $p4 : expr, $p5 : expr, $p6 : expr, $p7 : expr,
+        $p8 : expr, $p9 : expr, $p10 : expr, $p11 : expr, $p12 : expr, $p13 : expr, $p14 : expr,
+        $p15 : expr, $p16 : expr, $p17 : expr, $p18 : expr, $p19 : expr, $p20 : expr,
+        $p21 : expr, $p22 : expr, $p23 : expr, $p24 : expr, $($rest : expr),+ $(,)?
+    ) => {
+        $crate::parser::choice::choice((
+            $p1, $p2, $p3, $p4, $p5, $p6, $p7, $p8, $p9, $p10, $p11, $p12, $p13, $p14, $p15, $p16,
+            $p17, $p18, $p19, $p20, $p21, $p22, $p23, $p24,
+            $crate::choice!($($rest),+),
+        ))
+    };
+    ($first : expr, $($rest : expr),+ $(,)?) => {
+        $crate::parser::choice::choice(($first, $($rest),+))
     }
 }
 
@@ -517,7 +540,40 @@ where
     }
 }
 
-/// Takes a tuple, a slice or an array of parsers and tries to apply them each in order.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<Input, O, P> ChoiceParser<Input> for Vec<P>
+where
+    Input: Stream,
+    P: Parser<Input, Output = O>,
+{
+    type Output = O;
+    type PartialState = <[P] as ChoiceParser<Input>>::PartialState;
+
+    parse_mode_choice!(Input);
+    #[inline]
+    fn parse_mode_choice<M>(
+        &mut self,
+        mode: M,
+        input: &mut Input,
+        state: &mut Self::PartialState,
+    ) -> ParseResult<Self::Output, <Input as StreamOnce>::Error>
+    where
+        M: ParseMode,
+    {
+        if mode.is_first() {
+            self[..].parse_first(input, state)
+        } else {
+            self[..].parse_partial(input, state)
+        }
+    }
+
+    fn add_error_choice(&mut self, error: &mut Tracked<<Input as StreamOnce>::Error>) {
+        self[..].add_error_choice(error)
+    }
+}
+
+/// Takes a tuple, a slice, an array or a `Vec` of parsers and tries to apply them each in order.
 /// Fails if all the parsers fails or if an applied parser consumes input before failing.
 ///
 /// ```
@@ -546,6 +602,12 @@ where
 /// // Use 'attempt' to make failing parsers always act as if they have not committed any input
 /// let mut parser3 = choice([attempt(string("one")), attempt(string("two")), attempt(string("three"))]);
 /// assert_eq!(parser3.parse("three"), Ok(("three", "")));
+///
+/// // A `Vec` can be used when the number of alternatives is only known at runtime
+/// let mut parser4 = choice(
+///     ["one", "two", "three"].iter().map(|s| attempt(string(*s))).collect::<Vec<_>>()
+/// );
+/// assert_eq!(parser4.parse("two"), Ok(("two", "")));
 /// # }
 /// ```
 pub fn choice<Input, P>(ps: P) -> Choice<P>
@@ -687,6 +749,300 @@ where
     Optional(parser)
 }
 
+#[derive(Copy, Clone)]
+pub struct OptOr<P, O>(Optional<P>, O);
+impl<Input, P> Parser<Input> for OptOr<P, P::Output>
+where
+    Input: Stream,
+    P: Parser<Input>,
+    P::Output: Clone,
+{
+    type Output = P::Output;
+    type PartialState = P::PartialState;
+
+    parse_mode!(Input);
+    #[inline]
+    fn parse_mode_impl<M>(
+        &mut self,
+        mode: M,
+        input: &mut Input,
+        state: &mut Self::PartialState,
+    ) -> ParseResult<Self::Output, <Input as StreamOnce>::Error>
+    where
+        M: ParseMode,
+    {
+        let default = &self.1;
+        self.0
+            .parse_mode(mode, input, state)
+            .map(|x| x.unwrap_or_else(|| default.clone()))
+    }
+
+    forward_parser!(Input, add_error parser_count, 0);
+}
+
+/// Parses `parser` and outputs its value if it succeeds, or a clone of `default` if it fails
+/// without consuming any input. Fails if `parser` fails after having committed some input.
+///
+/// Equivalent to `optional(parser).map(|o| o.unwrap_or(default))`. Pass `Default::default()` as
+/// `default` to fall back to the default value of the output.
+///
+/// ```
+/// # extern crate combine;
+/// # use combine::*;
+/// # use combine::parser::char::{char, digit};
+/// # use combine::parser::choice::opt_or;
+/// # fn main() {
+/// let mut parser = opt_or(char('-').map(|_| -1), 1);
+/// assert_eq!(parser.parse("-2"), Ok((-1, "2")));
+/// assert_eq!(parser.parse("2"), Ok((1, "2")));
+///
+/// let mut parser = opt_or(many1(digit()), Default::default());
+/// assert_eq!(parser.parse("x"), Ok((String::new(), "x")));
+/// # }
+/// ```
+pub fn opt_or<Input, P>(parser: P, default: P::Output) -> OptOr<P, P::Output>
+where
+    Input: Stream,
+    P: Parser<Input>,
+    P::Output: Clone,
+{
+    OptOr(optional(parser), default)
+}
+
+/// `PermutationParser` represents a tuple of parsers which may each match once, in any order.
+///
+/// This is an internal trait used to overload the `permutation` function.
+pub trait PermutationParser<Input: Stream> {
+    type Output;
+    type PartialState: Default;
+
+    fn parse_mode_permutation<M>(
+        &mut self,
+        mode: M,
+        input: &mut Input,
+        state: &mut Self::PartialState,
+    ) -> ParseResult<Self::Output, <Input as StreamOnce>::Error>
+    where
+        M: ParseMode;
+
+    fn add_error_permutation(&mut self, error: &mut Tracked<<Input as StreamOnce>::Error>);
+}
+
+macro_rules! tuple_permutation_parser {
+    ($(($id: ident $index: tt))+) => {
+        #[allow(non_snake_case)]
+        impl<Input $(,$id)+> PermutationParser<Input> for ($($id,)+)
+        where
+            Input: Stream,
+            $($id: Parser<Input>),+
+        {
+            type Output = ($($id::Output,)+);
+            type PartialState = (usize, ($(Option<$id::Output>,)+), ($($id::PartialState,)+));
+
+            #[inline]
+            fn parse_mode_permutation<Mode>(
+                &mut self,
+                mode: Mode,
+                input: &mut Input,
+                state: &mut Self::PartialState,
+            ) -> ParseResult<Self::Output, <Input as StreamOnce>::Error>
+            where
+                Mode: ParseMode,
+            {
+                let ($(ref mut $id,)+) = *self;
+                let (ref mut current, ref mut outputs, ref mut states) = *state;
+                if mode.is_first() {
+                    *current = 0;
+                    $(
+                        outputs.$index = None;
+                    )+
+                }
+                // Any output we already hold (or a parser left in progress) means that an earlier
+                // call committed to this permutation
+                let mut committed = *current != 0 $(|| outputs.$index.is_some())+;
+
+                if *current != 0 {
+                    $(
+                        if *current == $index + 1 {
+                            match $id.parse_mode(mode, input, &mut states.$index) {
+                                CommitOk(x) | PeekOk(x) => outputs.$index = Some(x),
+                                CommitErr(err) => return CommitErr(err),
+                                PeekErr(err) => {
+                                    *current = 0;
+                                    return CommitErr(err.error);
+                                }
+                            }
+                        }
+                    )+
+                    *current = 0;
+                }
+
+                while !($(outputs.$index.is_some())&&+) {
+                    let before = input.checkpoint();
+                    let before_position = input.position();
+                    let mut error: Option<<Input as StreamOnce>::Error> = None;
+                    // Index (plus one) of a parser which succeeded without consuming any input.
+                    // It is only kept if none of the remaining parsers can consume input.
+                    let mut peek_ok = 0;
+                    let mut commit_ok = false;
+                    $(
+                        if !commit_ok && outputs.$index.is_none() {
+                            ctry!(input.reset(before.clone()).committed());
+                            match $id.parse_mode(crate::parser::FirstMode, input, &mut states.$index) {
+                                CommitOk(x) => {
+                                    outputs.$index = Some(x);
+                                    commit_ok = true;
+                                }
+                                PeekOk(x) => {
+                                    if peek_ok == 0 {
+                                        outputs.$index = Some(x);
+                                        peek_ok = $index + 1;
+                                    }
+                                }
+                                CommitErr(err) => {
+                                    // Same as in `choice`, only resume this parser if it actually
+                                    // consumed some input
+                                    if input.position() != before_position {
+                                        *current = $index + 1;
+                                    }
+                                    return CommitErr(err);
+                                }
+                                PeekErr(mut err) => {
+                                    if committed || err.offset != ErrorOffset(1) {
+                                        $id.add_error(&mut err);
+                                    }
+                                    error = Some(match error {
+                                        None => err.error,
+                                        Some(error) => error.merge(err.error),
+                                    });
+                                }
+                            }
+                        }
+                    )+
+
+                    if commit_ok {
+                        committed = true;
+                        $(
+                            if peek_ok == $index + 1 {
+                                outputs.$index = None;
+                            }
+                        )+
+                    } else if peek_ok == 0 {
+                        ctry!(input.reset(before.clone()).committed());
+                        let mut error = error.expect("permutation to have missing parsers");
+                        if !committed {
+                            return PeekErr(error.into());
+                        }
+                        // Earlier parsers committed so the errors which would normally be added
+                        // through `add_error` have been added above, except for the token itself
+                        if let Ok(t) = input.uncons() {
+                            ctry!(input.reset(before).committed());
+                            error.add(StreamError::unexpected_token(t));
+                        }
+                        return CommitErr(error);
+                    } else {
+                        ctry!(input.reset(before).committed());
+                    }
+                }
+
+                let output = ($(outputs.$index.take().expect("permutation output"),)+);
+                if committed {
+                    CommitOk(output)
+                } else {
+                    PeekOk(output)
+                }
+            }
+
+            fn add_error_permutation(
+                &mut self,
+                error: &mut Tracked<<Input as StreamOnce>::Error>
+            ) {
+                if error.offset != ErrorOffset(0) {
+                    let ($(ref mut $id,)+) = *self;
+                    $(
+                        error.offset = ErrorOffset(1);
+                        $id.add_error(error);
+                    )+
+                }
+            }
+        }
+    };
+}
+
+macro_rules! tuple_permutation_parsers {
+    ([$($prev: tt)*]) => {};
+    ([$($prev: tt)*] $head: tt $($tail: tt)*) => {
+        tuple_permutation_parser!($($prev)* $head);
+        tuple_permutation_parsers!([$($prev)* $head] $($tail)*);
+    };
+}
+
+tuple_permutation_parsers!([] (A 0) (B 1) (C 2) (D 3) (E 4) (F 5) (G 6) (H 7) (I 8) (J 9) (K 10) (L 11));
+
+#[derive(Copy, Clone)]
+pub struct Permutation<P>(P);
+
+impl<Input, P> Parser<Input> for Permutation<P>
+where
+    Input: Stream,
+    P: PermutationParser<Input>,
+{
+    type Output = P::Output;
+    type PartialState = P::PartialState;
+
+    parse_mode!(Input);
+    #[inline]
+    fn parse_mode_impl<M>(
+        &mut self,
+        mode: M,
+        input: &mut Input,
+        state: &mut Self::PartialState,
+    ) -> ParseResult<Self::Output, <Input as StreamOnce>::Error>
+    where
+        M: ParseMode,
+    {
+        self.0.parse_mode_permutation(mode, input, state)
+    }
+
+    fn add_error(&mut self, error: &mut Tracked<<Input as StreamOnce>::Error>) {
+        let before = error.offset.0;
+        self.0.add_error_permutation(error);
+        error.offset.0 = before.saturating_sub(1);
+    }
+}
+
+/// Takes a tuple of parsers and applies each of them exactly once, in whatever order they match
+/// the input. The outputs are returned in the same order as the parsers in the tuple.
+///
+/// At each step the parsers which have not matched yet are tried in order. A parser which
+/// succeeds without consuming input is only accepted if none of the others can consume input.
+/// Fails if a parser fails after committing to its parse or if none of the remaining parsers
+/// match, which is also how a repeated component is reported.
+///
+/// ```
+/// # extern crate combine;
+/// # use combine::*;
+/// # use combine::parser::char::{char, digit, spaces};
+/// # use combine::parser::choice::permutation;
+/// # fn main() {
+/// let attribute = |name| char(name).skip(char('=')).with(digit()).skip(spaces());
+/// let mut parser = permutation((attribute('a'), attribute('b')));
+/// assert_eq!(parser.parse("a=1 b=2"), Ok((('1', '2'), "")));
+/// assert_eq!(parser.parse("b=2 a=1"), Ok((('1', '2'), "")));
+/// // `b` is missing
+/// assert!(parser.parse("a=1").is_err());
+/// // `a` is given twice
+/// assert!(parser.parse("a=1 a=2 b=3").is_err());
+/// # }
+/// ```
+pub fn permutation<Input, P>(ps: P) -> Permutation<P>
+where
+    Input: Stream,
+    P: PermutationParser<Input>,
+{
+    Permutation(ps)
+}
+
 #[macro_export]
 #[doc(hidden)]
 macro_rules! parse_mode_dispatch {
@@ -815,6 +1171,29 @@ macro_rules! dispatch_inner {
 /// assert_eq!(parser.easy_parse("cd"), Ok(('d', "")));
 /// assert!(parser.easy_parse("ab").is_err());
 /// ```
+///
+/// Peeking at the next token with `look_ahead` instead selects a branch in a single `match`
+/// rather than trying each alternative in turn, which is useful when a grammar is driven by
+/// keywords or tags. Once a branch is selected no other branch is tried if it fails.
+///
+/// ```
+/// use combine::{dispatch, any, look_ahead, EasyParser, Parser};
+/// use combine::parser::char::{digit, string};
+/// use combine::parser::error::unexpected_any;
+///
+/// let mut parser = look_ahead(any()).then(|c| {
+///     dispatch!(c;
+///         't' => string("true").map(|_| Some(true)),
+///         'f' => string("false").map(|_| Some(false)),
+///         'n' => string("null").map(|_| None),
+///         _ => unexpected_any(c).map(|()| None),
+///     )
+/// });
+/// assert_eq!(parser.easy_parse("false"), Ok((Some(false), "")));
+/// assert_eq!(parser.easy_parse("null"), Ok((None, "")));
+/// assert!(parser.easy_parse("nil").is_err());
+/// assert!(parser.easy_parse("1").is_err());
+/// ```
 #[macro_export]
 macro_rules! dispatch {
     ($match_expr: expr; $( $($pat: pat)|+ $(if $pred:expr)? => $expr: expr ),+ $(,)? ) => {
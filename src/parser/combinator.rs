@@ -6,7 +6,7 @@ use crate::{
         ParseResult::{self, *},
         ResultExt, StreamError, Tracked,
     },
-    lib::{fmt, marker::PhantomData, mem, str},
+    lib::{fmt, marker::PhantomData, mem, ops, str},
     parser::ParseMode,
     stream::{input_at_eof, ResetStream, Stream, StreamErrorFor, StreamOnce},
     Parser,
@@ -18,6 +18,7 @@ impl<Input, O, P> Parser<Input> for NotFollowedBy<P>
 where
     Input: Stream,
     P: Parser<Input, Output = O>,
+    O: Into<Info<<Input as StreamOnce>::Token, <Input as StreamOnce>::Range, &'static str>>,
 {
     type Output = ();
     type PartialState = P::PartialState;
@@ -37,7 +38,11 @@ where
         let result = self.0.parse_mode(mode, input, state);
         ctry!(input.reset(checkpoint).committed());
         match result {
-            CommitOk(_) | PeekOk(_) => PeekErr(Input::Error::empty(input.position()).into()),
+            CommitOk(output) | PeekOk(output) => {
+                let mut error = Input::Error::empty(input.position());
+                error.add(StreamError::unexpected(output.into()));
+                PeekErr(error.into())
+            }
             CommitErr(_) | PeekErr(_) => PeekOk(()),
         }
     }
@@ -52,7 +57,7 @@ where
 }
 
 /// Succeeds only if `parser` fails.
-/// Never consumes any input.
+/// Never consumes any input. If `parser` succeeds its output is reported as unexpected.
 ///
 /// ```
 /// # extern crate combine;
@@ -158,6 +163,83 @@ where
     Try(p)
 }
 
+#[derive(Copy, Clone)]
+pub struct Cut<P>(P);
+impl<Input, P> Parser<Input> for Cut<P>
+where
+    Input: Stream,
+    P: Parser<Input>,
+{
+    type Output = P::Output;
+    type PartialState = P::PartialState;
+
+    parse_mode!(Input);
+    #[inline]
+    fn parse_mode_impl<M>(
+        &mut self,
+        mode: M,
+        input: &mut Input,
+        state: &mut Self::PartialState,
+    ) -> ParseResult<Self::Output, <Input as StreamOnce>::Error>
+    where
+        M: ParseMode,
+    {
+        let before = input.checkpoint();
+        match self.0.parse_mode(mode, input, state) {
+            CommitOk(x) => CommitOk(x),
+            PeekOk(x) => PeekOk(x),
+            CommitErr(err) => CommitErr(err),
+            // As no one will call `add_error` on a committed error the complete error must be built
+            // here
+            PeekErr(mut err) => {
+                ctry!(input.reset(before.clone()).committed());
+                if let Ok(t) = input.uncons() {
+                    ctry!(input.reset(before).committed());
+                    err.error.add(StreamError::unexpected_token(t));
+                }
+                self.0.add_error(&mut err);
+                CommitErr(err.error)
+            }
+        }
+    }
+
+    forward_parser!(Input, add_error add_committed_expected_error parser_count, 0);
+}
+
+/// `cut(p)` behaves as `p` except that if `p` fails without consuming any input it is treated as
+/// if it had committed to its parse. Alternatives in `or` and `choice` are therefore not tried
+/// after a `cut` fails, so the error from inside the chosen branch is reported instead of the
+/// errors of every alternative.
+///
+/// A parser which consumes input before failing has already committed, so `cut` is only needed
+/// when a branch must be final before it has consumed anything, for instance after a keyword that
+/// was only peeked at with `look_ahead`. `cut` is the opposite of [`attempt`][] which still
+/// backtracks out of a failed `cut`.
+///
+/// ```
+/// # extern crate combine;
+/// # use combine::*;
+/// # use combine::parser::char::{digit, letter};
+/// # use combine::parser::combinator::cut;
+/// # fn main() {
+/// let mut parser = cut(digit()).or(letter());
+/// assert_eq!(parser.parse("1"), Ok(('1', "")));
+/// assert!(parser.parse("a").is_err());
+///
+/// let mut parser = attempt(cut(digit())).or(letter());
+/// assert_eq!(parser.parse("a"), Ok(('a', "")));
+/// # }
+/// ```
+///
+/// [`attempt`]: fn.attempt.html
+pub fn cut<Input, P>(p: P) -> Cut<P>
+where
+    Input: Stream,
+    P: Parser<Input>,
+{
+    Cut(p)
+}
+
 #[derive(Copy, Clone)]
 pub struct LookAhead<P>(P);
 
@@ -295,6 +377,106 @@ where
     MapInput(p, f)
 }
 
+#[derive(Copy, Clone)]
+pub struct Spanned<P>(P);
+impl<Input, P> Parser<Input> for Spanned<P>
+where
+    Input: Stream,
+    P: Parser<Input>,
+{
+    type Output = (ops::Range<Input::Position>, P::Output);
+    type PartialState = (Option<Input::Position>, P::PartialState);
+
+    parse_mode!(Input);
+    #[inline]
+    fn parse_mode_impl<M>(
+        &mut self,
+        mode: M,
+        input: &mut Input,
+        state: &mut Self::PartialState,
+    ) -> ParseResult<Self::Output, <Input as StreamOnce>::Error>
+    where
+        M: ParseMode,
+    {
+        let (ref mut start, ref mut child_state) = *state;
+        if mode.is_first() || start.is_none() {
+            *start = Some(input.position());
+        }
+        match self.0.parse_mode(mode, input, child_state) {
+            CommitOk(x) => {
+                let start = start.take().unwrap();
+                CommitOk((start..input.position(), x))
+            }
+            PeekOk(x) => {
+                let start = start.take().unwrap();
+                PeekOk((start..input.position(), x))
+            }
+            // Keep the start position so that a partial parse can resume from it
+            CommitErr(err) => CommitErr(err),
+            PeekErr(err) => {
+                *start = None;
+                PeekErr(err)
+            }
+        }
+    }
+
+    forward_parser!(Input, add_error add_committed_expected_error parser_count, 0);
+}
+
+/// Equivalent to [`p.spanned()`].
+///
+/// [`p.spanned()`]: ../trait.Parser.html#method.spanned
+pub fn spanned<Input, P>(p: P) -> Spanned<P>
+where
+    Input: Stream,
+    P: Parser<Input>,
+{
+    Spanned(p)
+}
+
+#[derive(Copy, Clone)]
+pub struct MapWithPosition<P, F>(Spanned<P>, F);
+impl<Input, A, B, P, F> Parser<Input> for MapWithPosition<P, F>
+where
+    Input: Stream,
+    P: Parser<Input, Output = A>,
+    F: FnMut(A, Input::Position) -> B,
+{
+    type Output = B;
+    type PartialState = <Spanned<P> as Parser<Input>>::PartialState;
+
+    parse_mode!(Input);
+    #[inline]
+    fn parse_mode_impl<M>(
+        &mut self,
+        mode: M,
+        input: &mut Input,
+        state: &mut Self::PartialState,
+    ) -> ParseResult<Self::Output, <Input as StreamOnce>::Error>
+    where
+        M: ParseMode,
+    {
+        let f = &mut self.1;
+        self.0
+            .parse_mode(mode, input, state)
+            .map(|(span, x)| f(x, span.start))
+    }
+
+    forward_parser!(Input, add_error add_committed_expected_error parser_count, 0);
+}
+
+/// Equivalent to [`p.map_with_position(f)`].
+///
+/// [`p.map_with_position(f)`]: ../trait.Parser.html#method.map_with_position
+pub fn map_with_position<Input, P, F, B>(p: P, f: F) -> MapWithPosition<P, F>
+where
+    Input: Stream,
+    P: Parser<Input>,
+    F: FnMut(P::Output, Input::Position) -> B,
+{
+    MapWithPosition(spanned(p), f)
+}
+
 #[derive(Copy, Clone)]
 pub struct FlatMap<P, F>(P, F);
 impl<Input, A, B, P, F> Parser<Input> for FlatMap<P, F>
@@ -979,6 +1161,149 @@ where
     Lazy(p)
 }
 
+#[cfg(feature = "std")]
+type ForwardRefParser<'a, Input, O> =
+    Box<dyn Parser<Input, Output = O, PartialState = AnyPartialState> + 'a>;
+
+#[cfg(feature = "std")]
+type ForwardRefFactory<'a, Input, O> = std::rc::Rc<dyn Fn() -> ForwardRefParser<'a, Input, O> + 'a>;
+
+#[cfg(feature = "std")]
+pub struct ForwardRef<'a, Input, O>(
+    std::rc::Rc<std::cell::RefCell<Option<ForwardRefFactory<'a, Input, O>>>>,
+);
+
+#[cfg(feature = "std")]
+impl<'a, Input, O> Clone for ForwardRef<'a, Input, O> {
+    fn clone(&self) -> Self {
+        ForwardRef(self.0.clone())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, Input, O> ForwardRef<'a, Input, O>
+where
+    Input: Stream,
+{
+    /// Defines the parser which this handle (and all of its clones) parses with. Like [`lazy`][],
+    /// `f` is called to construct the parser each time parsing starts, so the parser it returns
+    /// may itself contain handles to this `ForwardRef`.
+    ///
+    /// Calling `define` again replaces the previous definition.
+    ///
+    /// [`lazy`]: fn.lazy.html
+    pub fn define<F, P>(&self, f: F)
+    where
+        F: Fn() -> P + 'a,
+        P: Parser<Input, Output = O> + 'a,
+        P::PartialState: 'static,
+    {
+        *self.0.borrow_mut() = Some(std::rc::Rc::new(move || Box::new(any_partial_state(f()))));
+    }
+
+    fn parser(&self) -> ForwardRefParser<'a, Input, O> {
+        // Release the borrow before the parser is constructed and used as it may recursively refer
+        // back to this handle
+        let factory = self
+            .0
+            .borrow()
+            .clone()
+            .expect("ForwardRef was used before a parser was defined for it");
+        factory()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, Input, O> Parser<Input> for ForwardRef<'a, Input, O>
+where
+    Input: Stream,
+{
+    type Output = O;
+    type PartialState = AnyPartialState;
+
+    fn parse_stream(&mut self, input: &mut Input) -> ParseResult<O, <Input as StreamOnce>::Error> {
+        self.parser().parse_stream(input)
+    }
+
+    fn parse_lazy(&mut self, input: &mut Input) -> ParseResult<O, <Input as StreamOnce>::Error> {
+        self.parser().parse_lazy(input)
+    }
+
+    parse_mode!(Input);
+
+    fn parse_committed_mode<M>(
+        &mut self,
+        mode: M,
+        input: &mut Input,
+        state: &mut Self::PartialState,
+    ) -> ParseResult<Self::Output, <Input as StreamOnce>::Error>
+    where
+        M: ParseMode,
+    {
+        self.parser().parse_mode(mode, input, state)
+    }
+
+    fn parse_mode_impl<M>(
+        &mut self,
+        mode: M,
+        input: &mut Input,
+        state: &mut Self::PartialState,
+    ) -> ParseResult<Self::Output, <Input as StreamOnce>::Error>
+    where
+        M: ParseMode,
+    {
+        self.parser().parse_mode(mode, input, state)
+    }
+
+    fn add_error(&mut self, errors: &mut Tracked<<Input as StreamOnce>::Error>) {
+        self.parser().add_error(errors);
+    }
+
+    fn add_committed_expected_error(&mut self, errors: &mut Tracked<<Input as StreamOnce>::Error>) {
+        self.parser().add_committed_expected_error(errors);
+    }
+}
+
+/// Creates a handle to a parser which is defined later through [`ForwardRef::define`][]. Since the
+/// handle can be cloned and used before it is defined it allows recursive (and mutually recursive)
+/// grammars to be built from closures which capture their configuration, instead of needing to be
+/// written as functions.
+///
+/// Note that a definition which refers back to its own handle forms a reference cycle, so the
+/// definition is never freed.
+///
+/// ```
+/// # extern crate combine;
+/// # use combine::*;
+/// # use combine::parser::char::{char, digit};
+/// # use combine::parser::combinator::forward_ref;
+/// # fn main() {
+/// let separator = '+';
+///
+/// let expr = forward_ref::<&str, i32>();
+/// let nested = expr.clone();
+/// expr.define(move || {
+///     let atom = many1(digit())
+///         .map(|digits: String| digits.parse().unwrap())
+///         .or(between(char('('), char(')'), nested.clone()));
+///     sep_by1(atom, char(separator)).map(|atoms: Vec<i32>| atoms.iter().sum())
+/// });
+///
+/// let mut parser = expr.clone();
+/// assert_eq!(parser.parse("1+(2+(3))+4"), Ok((10, "")));
+/// assert!(parser.parse("1+(2").is_err());
+/// # }
+/// ```
+///
+/// [`ForwardRef::define`]: struct.ForwardRef.html#method.define
+#[cfg(feature = "std")]
+pub fn forward_ref<'a, Input, O>() -> ForwardRef<'a, Input, O>
+where
+    Input: Stream,
+{
+    ForwardRef(Default::default())
+}
+
 #[derive(Copy, Clone)]
 pub struct Factory<P, R>(P, Option<R>);
 
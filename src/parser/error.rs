@@ -92,6 +92,61 @@ where
     Unexpected(message, PhantomData)
 }
 
+#[derive(Clone)]
+pub struct Fail<I, T, E>(E, PhantomData<fn(I) -> (I, T)>)
+where
+    I: Stream;
+impl<Input, T, E> Parser<Input> for Fail<Input, T, E>
+where
+    Input: Stream,
+    E: for<'s> ErrorInfo<'s, Input::Token, Input::Range>,
+{
+    type Output = T;
+    type PartialState = ();
+    #[inline]
+    fn parse_lazy(&mut self, input: &mut Input) -> ParseResult<T, <Input as StreamOnce>::Error> {
+        PeekErr(<Input as StreamOnce>::Error::empty(input.position()).into())
+    }
+    fn add_error(&mut self, errors: &mut Tracked<<Input as StreamOnce>::Error>) {
+        errors.error.add(StreamError::message(&self.0));
+    }
+}
+/// Always fails with `message` as the error message.
+/// Never consumes any input.
+///
+/// May have anything as the output type but must be used such that the output type can inferred.
+///
+/// ```
+/// # extern crate combine;
+/// # use combine::*;
+/// # use combine::parser::char::digit;
+/// # use combine::parser::error::fail;
+/// # use combine::stream::easy;
+/// # fn main() {
+/// let mut parser = digit().then(|d| {
+///     if d == '0' {
+///         fail("leading zeros are not allowed").left()
+///     } else {
+///         value(d).right()
+///     }
+/// });
+/// assert_eq!(parser.easy_parse("1").map(|x| x.0), Ok('1'));
+/// assert!(
+///     parser.easy_parse("0")
+///         .unwrap_err()
+///         .errors
+///         .contains(&easy::Error::Message("leading zeros are not allowed".into()))
+/// );
+/// # }
+/// ```
+pub fn fail<Input, S, T>(message: S) -> Fail<Input, T, S>
+where
+    Input: Stream,
+    S: for<'s> ErrorInfo<'s, Input::Token, Input::Range>,
+{
+    Fail(message, PhantomData)
+}
+
 #[derive(Clone)]
 pub struct Message<P, S>(P, S);
 impl<Input, P, S> Parser<Input> for Message<P, S>
@@ -243,3 +298,50 @@ where
 {
     Silent(p)
 }
+
+#[derive(Clone)]
+pub struct MapErr<P, F>(P, F);
+impl<Input, P, F> Parser<Input> for MapErr<P, F>
+where
+    P: Parser<Input>,
+    Input: Stream,
+    F: FnMut(<Input as StreamOnce>::Error) -> <Input as StreamOnce>::Error,
+{
+    type Output = P::Output;
+    type PartialState = P::PartialState;
+
+    parse_mode!(Input);
+    #[inline]
+    fn parse_mode_impl<M>(
+        &mut self,
+        mode: M,
+        input: &mut Input,
+        state: &mut Self::PartialState,
+    ) -> ParseResult<Self::Output, <Input as StreamOnce>::Error>
+    where
+        M: ParseMode,
+    {
+        match self.0.parse_mode(mode, input, state) {
+            CommitOk(x) => CommitOk(x),
+            PeekOk(x) => PeekOk(x),
+            CommitErr(err) => CommitErr((self.1)(err)),
+            // Errors which did not commit are built lazily through `add_error` so they are left
+            // untouched to let the surrounding parsers complete (or silence) them
+            PeekErr(err) => PeekErr(err),
+        }
+    }
+
+    forward_parser!(Input, add_error add_committed_expected_error parser_count, 0);
+}
+
+/// Equivalent to [`p.map_err(f)`].
+///
+/// [`p.map_err(f)`]: ../trait.Parser.html#method.map_err
+pub fn map_err<Input, P, F>(p: P, f: F) -> MapErr<P, F>
+where
+    P: Parser<Input>,
+    Input: Stream,
+    F: FnMut(<Input as StreamOnce>::Error) -> <Input as StreamOnce>::Error,
+{
+    MapErr(p, f)
+}
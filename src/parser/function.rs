@@ -58,6 +58,27 @@ pub struct FnParser<Input, F>(F, PhantomData<fn(Input) -> Input>);
 /// assert_eq!(result, Ok(8));
 /// # }
 /// ```
+///
+/// As the closure may capture its environment, `parser` is also a way to thread configuration
+/// through a parser without casting a function to a `fn` pointer. The returned parser can be used
+/// with any of the other combinators.
+///
+/// ```
+/// # extern crate combine;
+/// # use combine::*;
+/// # use combine::parser::char::{alpha_num, char};
+/// # fn main() {
+/// let radix = 16;
+/// let number = parser(|input: &mut &str| {
+///     let (digits, committed) = many1::<String, _, _>(alpha_num())
+///         .parse_stream(input)
+///         .into_result()?;
+///     Ok((u32::from_str_radix(&digits, radix).unwrap_or(0), committed))
+/// });
+/// let mut parser = sep_by(number, char(','));
+/// assert_eq!(parser.parse("ff,10"), Ok((vec![255, 16], "")));
+/// # }
+/// ```
 pub fn parser<Input, O, F>(f: F) -> FnParser<Input, F>
 where
     Input: Stream,
@@ -10,8 +10,11 @@ use crate::{
         ResultExt, Token, Tracked,
     },
     parser::{
-        combinator::{and_then, flat_map, map, map_input, AndThen, Either, FlatMap, Map, MapInput},
-        error::{expected, message, silent, Expected, Message, Silent},
+        combinator::{
+            and_then, flat_map, map, map_input, map_with_position, spanned, AndThen, Either,
+            FlatMap, Map, MapInput, MapWithPosition, Spanned,
+        },
+        error::{expected, map_err, message, silent, Expected, MapErr, Message, Silent},
         repeat::Iter,
         sequence::{then, then_partial, Then, ThenPartial},
     },
@@ -20,7 +23,7 @@ use crate::{
 };
 
 use self::{
-    choice::{or, Or},
+    choice::{opt_or, or, OptOr, Or},
     sequence::{skip, with, Skip, With},
 };
 
@@ -106,6 +109,36 @@ pub trait Parser<Input: Stream> {
         }
     }
 
+    /// Entry point of the parser which requires that the entire input is consumed.
+    ///
+    /// Equivalent to `(self, eof()).map(|(x, _)| x).parse(input)` except that only the parsed
+    /// result is returned since the remaining input is always empty.
+    ///
+    /// ```
+    /// # extern crate combine;
+    /// # use combine::*;
+    /// # use combine::parser::char::digit;
+    /// # use combine::stream::easy;
+    /// # use combine::stream::position::{self, SourcePosition};
+    /// # fn main() {
+    /// let result = many1::<String, _, _>(digit()).parse_all("123");
+    /// assert_eq!(result, Ok("123".to_string()));
+    ///
+    /// let result = many1::<String, _, _>(digit())
+    ///     .parse_all(easy::Stream(position::Stream::new("123abc")));
+    /// assert_eq!(result.map_err(|err| err.position), Err(SourcePosition { line: 1, column: 4 }));
+    /// # }
+    /// ```
+    fn parse_all(&mut self, input: Input) -> Result<Self::Output, <Input as StreamOnce>::Error>
+    where
+        Self: Sized,
+    {
+        (self.by_ref(), token::eof())
+            .map(|(x, _)| x)
+            .parse(input)
+            .map(|(x, _)| x)
+    }
+
     /// Entry point of the parser when using partial parsing.
     /// Takes some input and tries to parse it.
     ///
@@ -397,6 +430,29 @@ pub trait Parser<Input: Stream> {
         skip(self, p)
     }
 
+    /// Discards any whitespace following `self` and returns the value of `self`. The same as
+    /// `self.skip(spaces())`.
+    ///
+    /// ```
+    /// # extern crate combine;
+    /// # use combine::*;
+    /// # use combine::parser::char::{digit, letter};
+    /// # fn main() {
+    /// let result = (letter().skip_spaces(), digit())
+    ///     .parse("x \n 1")
+    ///     .map(|x| x.0);
+    /// assert_eq!(result, Ok(('x', '1')));
+    /// # }
+    /// ```
+    fn skip_spaces(self) -> Skip<Self, char::Spaces<Input>>
+    where
+        Self: Sized,
+        Input: Stream<Token = char>,
+        Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+    {
+        skip(self, char::spaces())
+    }
+
     /// Parses with `self` followed by `p`.
     /// Succeeds if both parsers succeed, otherwise fails.
     /// Returns a tuple with both values on success.
@@ -458,6 +514,28 @@ pub trait Parser<Input: Stream> {
         or(self, p)
     }
 
+    /// Equivalent to [`opt_or(self, default)`].
+    ///
+    /// ```
+    /// # extern crate combine;
+    /// # use combine::*;
+    /// # use combine::parser::char::{char, spaces};
+    /// # fn main() {
+    /// let mut parser = char(',').map(Some).or_value(None).skip(spaces());
+    /// assert_eq!(parser.parse(", x"), Ok((Some(','), "x")));
+    /// assert_eq!(parser.parse(" x"), Ok((None, "x")));
+    /// # }
+    /// ```
+    ///
+    /// [`opt_or(self, default)`]: choice/fn.opt_or.html
+    fn or_value(self, default: Self::Output) -> OptOr<Self, Self::Output>
+    where
+        Self: Sized,
+        Self::Output: Clone,
+    {
+        opt_or(self, default)
+    }
+
     /// Parses using `self` and then passes the value to `f` which returns a parser used to parse
     /// the rest of the input.
     ///
@@ -557,6 +635,29 @@ pub trait Parser<Input: Stream> {
         map(self, f)
     }
 
+    /// Uses `f` to map over the parsed value, also giving `f` access to the input as it is after
+    /// `self` succeeded. Use [`flat_map`] instead if the mapping can fail, or [`then`] if it needs
+    /// to parse more input.
+    ///
+    /// ```
+    /// # #![cfg(feature = "std")]
+    /// # extern crate combine;
+    /// # use combine::*;
+    /// # use combine::parser::char::letter;
+    /// # use combine::stream::position::{self, SourcePosition};
+    /// # fn main() {
+    /// let result = many1::<String, _, _>(letter())
+    ///     .map_input(|word, input: &mut position::Stream<&str, SourcePosition>| {
+    ///         (word, input.position())
+    ///     })
+    ///     .parse(position::Stream::new("let x"))
+    ///     .map(|x| x.0);
+    /// assert_eq!(result, Ok(("let".to_string(), SourcePosition { line: 1, column: 4 })));
+    /// # }
+    /// ```
+    ///
+    /// [`flat_map`]: trait.Parser.html#method.flat_map
+    /// [`then`]: trait.Parser.html#method.then
     fn map_input<F, B>(self, f: F) -> MapInput<Self, F>
     where
         Self: Sized,
@@ -565,6 +666,73 @@ pub trait Parser<Input: Stream> {
         map_input(self, f)
     }
 
+    /// Parses with `self` and returns its output together with the range of positions it spanned,
+    /// from the position before `self` ran to the position after it succeeded.
+    ///
+    /// ```
+    /// # #![cfg(feature = "std")]
+    /// # extern crate combine;
+    /// # use combine::*;
+    /// # use combine::parser::char::{integer, spaces};
+    /// # use combine::stream::position::{self, SourcePosition};
+    /// # fn main() {
+    /// let result = spaces()
+    ///     .with(integer().spanned())
+    ///     .parse(position::Stream::new("  123"))
+    ///     .map(|x| x.0);
+    /// assert_eq!(
+    ///     result,
+    ///     Ok((
+    ///         SourcePosition { line: 1, column: 3 }..SourcePosition { line: 1, column: 6 },
+    ///         123
+    ///     ))
+    /// );
+    /// # }
+    /// ```
+    fn spanned(self) -> Spanned<Self>
+    where
+        Self: Sized,
+    {
+        spanned(self)
+    }
+
+    /// Uses `f` to map over the output of `self`, also passing the position where `self` started
+    /// parsing. Useful for building syntax tree nodes which carry their location.
+    ///
+    /// ```
+    /// # #![cfg(feature = "std")]
+    /// # extern crate combine;
+    /// # use combine::*;
+    /// # use combine::parser::char::{letter, spaces};
+    /// # use combine::stream::position::{self, SourcePosition};
+    /// # fn main() {
+    /// #[derive(Debug, PartialEq)]
+    /// struct Ident {
+    ///     name: String,
+    ///     position: SourcePosition,
+    /// }
+    ///
+    /// let result = spaces()
+    ///     .with(many1(letter()).map_with_position(|name, position| Ident { name, position }))
+    ///     .parse(position::Stream::new("\n  x"))
+    ///     .map(|x| x.0);
+    /// assert_eq!(
+    ///     result,
+    ///     Ok(Ident {
+    ///         name: "x".to_string(),
+    ///         position: SourcePosition { line: 2, column: 3 },
+    ///     })
+    /// );
+    /// # }
+    /// ```
+    fn map_with_position<F, B>(self, f: F) -> MapWithPosition<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Output, Input::Position) -> B,
+    {
+        map_with_position(self, f)
+    }
+
     /// Uses `f` to map over the output of `self`. If `f` returns an error the parser fails.
     ///
     /// ```
@@ -688,6 +856,43 @@ pub trait Parser<Input: Stream> {
         silent(self)
     }
 
+    /// Parses with `self` and applies `f` to the error if `self` fails after committing to its
+    /// input, allowing the error to be rewritten before it is returned.
+    ///
+    /// Errors from failing without consuming any input are not passed to `f` as they are built
+    /// lazily so that `expected` and `silent` can still alter them. Use `expected` or `message`
+    /// to change those errors instead.
+    ///
+    /// ```
+    /// # #![cfg(feature = "std")]
+    /// # extern crate combine;
+    /// # use combine::*;
+    /// # use combine::parser::char::{hex_digit, string};
+    /// # use combine::stream::easy;
+    /// # use combine::stream::position::{self, SourcePosition};
+    /// # fn main() {
+    /// let result = string("0x")
+    ///     .with(many1::<String, _, _>(hex_digit()))
+    ///     .map_err(|mut err: easy::Errors<_, _, _>| {
+    ///         err.errors.clear();
+    ///         err.add_message("a hex number is required");
+    ///         err
+    ///     })
+    ///     .easy_parse(position::Stream::new("0xg"));
+    /// assert_eq!(result, Err(easy::Errors {
+    ///     position: SourcePosition { line: 1, column: 3 },
+    ///     errors: vec![easy::Error::Message("a hex number is required".into())]
+    /// }));
+    /// # }
+    /// ```
+    fn map_err<F>(self, f: F) -> MapErr<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(<Input as StreamOnce>::Error) -> <Input as StreamOnce>::Error,
+    {
+        map_err(self, f)
+    }
+
     /// Parses with `self` and applies `f` on the result if `self` parses successfully.
     /// `f` may optionally fail with an error which is automatically converted to a `ParseError`.
     ///
@@ -7,17 +7,17 @@
 
 use crate::{
     error::{
-        self, ParseError,
+        self, Commit, ParseError,
         ParseResult::{self, *},
         ResultExt, StreamError, Tracked,
     },
-    lib::marker::PhantomData,
-    parser::ParseMode,
+    lib::{convert::TryInto, marker::PhantomData},
+    parser::{function::parser, ParseMode},
 };
 
 use crate::stream::{
-    uncons_range, uncons_while, uncons_while1, wrap_stream_error, Range as StreamRange,
-    RangeStream, StreamOnce,
+    uncons_range, uncons_while, uncons_while1, wrap_stream_error, Positioned, Range as StreamRange,
+    RangeStream, Region, StreamErrorFor, StreamOnce,
 };
 
 use crate::Parser;
@@ -134,7 +134,7 @@ where
 
         let distance = input.distance(&before);
         ctry!(input.reset(before).committed());
-        take(distance).parse_lazy(input).map(|range| {
+        uncons_range(input, distance).map(|range| {
             *distance_state = 0;
             range
         })
@@ -227,6 +227,53 @@ where
     RecognizeWithValue(parser)
 }
 
+/// Parses with `parser` followed by `checksum` and succeeds if `f`, applied to the range consumed
+/// by `parser`, returns the checksum that was read.
+///
+/// A mismatch is reported as a "checksum mismatch" error at the start of the region.
+///
+/// ```
+/// # extern crate combine;
+/// # use combine::parser::byte::num::be_u16;
+/// # use combine::parser::range::{checksummed, length_prefixed, take_while};
+/// # use combine::parser::token::any;
+/// # use combine::*;
+/// # fn main() {
+/// // A length prefixed frame followed by the wrapping sum of all its bytes
+/// let mut frame = checksummed(
+///     length_prefixed(any(), take_while(|_| true)),
+///     any(),
+///     |region: &[u8]| region.iter().fold(0u8, |sum, b| sum.wrapping_add(*b)),
+/// );
+/// assert_eq!(frame.parse(&b"\x02\x10\x20\x32rest"[..]), Ok((&b"\x10\x20"[..], &b"rest"[..])));
+/// assert!(frame.parse(&b"\x02\x10\x20\x33"[..]).is_err());
+/// # }
+/// ```
+pub fn checksummed<Input, P, C, F>(
+    parser: P,
+    checksum: C,
+    mut f: F,
+) -> impl Parser<Input, Output = P::Output>
+where
+    Input: RangeStream,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+    Input::Range: StreamRange,
+    P: Parser<Input>,
+    C: Parser<Input>,
+    C::Output: PartialEq,
+    F: FnMut(Input::Range) -> C::Output,
+{
+    (recognize_with_value(parser), checksum).and_then(move |((region, value), expected)| {
+        if f(region) == expected {
+            Ok(value)
+        } else {
+            Err(StreamErrorFor::<Input>::message_static_message(
+                "checksum mismatch",
+            ))
+        }
+    })
+}
+
 /// Zero-copy parser which reads a range of length `i.len()` and succeeds if `i` is equal to that
 /// range.
 ///
@@ -257,6 +304,7 @@ pub struct Take<Input>(usize, PhantomData<fn(Input)>);
 impl<Input> Parser<Input> for Take<Input>
 where
     Input: RangeStream,
+    Input::Range: StreamRange,
 {
     type Output = Input::Range;
     type PartialState = ();
@@ -266,7 +314,22 @@ where
         &mut self,
         input: &mut Input,
     ) -> ParseResult<Self::Output, <Input as StreamOnce>::Error> {
-        uncons_range(input, self.0)
+        let before = input.checkpoint();
+        let mut result = uncons_range(input, self.0);
+        if let PeekErr(Tracked {
+            error: ref mut err, ..
+        })
+        | CommitErr(ref mut err) = result
+        {
+            // Only look at what is left once `uncons_range` has failed
+            ctry!(input.reset(before).committed());
+            err.add(StreamError::message_format(format_args!(
+                "expected {} elements but only {} were available",
+                self.0,
+                input.range().len()
+            )));
+        }
+        result
     }
 }
 
@@ -290,13 +353,90 @@ where
 /// assert!(result.is_err());
 /// # }
 /// ```
+///
+/// If there are fewer than `n` elements left the error reports how many were expected and how many
+/// were available.
+///
+/// ```
+/// # extern crate combine;
+/// # use combine::parser::range::take;
+/// # use combine::stream::{easy, position::{self, SourcePosition}};
+/// # use combine::*;
+/// # fn main() {
+/// let result = take(4).easy_parse(position::Stream::new(&b"ab"[..]));
+/// assert!(result.unwrap_err().errors.contains(&easy::Error::Message(
+///     "expected 4 elements but only 2 were available".to_string().into()
+/// )));
+/// # }
+/// ```
 pub fn take<Input>(n: usize) -> Take<Input>
 where
     Input: RangeStream,
+    Input::Range: StreamRange,
 {
     Take(n, PhantomData)
 }
 
+/// Parses a length with `len`, takes exactly that many elements and parses them with `body`.
+///
+/// `body` runs on a [`Region`] of the input which ends after the taken elements, so it sees the
+/// end of input at the end of the region and can never read past it. The parser fails if `body`
+/// fails or if it does not consume the whole region. Errors from `body` keep their positions in
+/// the input while elements left unparsed are reported where they start.
+///
+/// [`Region`]: ../../stream/struct.Region.html
+///
+/// ```
+/// # extern crate combine;
+/// # use combine::parser::byte::num::be_u16;
+/// # use combine::parser::range::{length_prefixed, take_while};
+/// # use combine::parser::token::any;
+/// # use combine::*;
+/// # fn main() {
+/// // A type byte followed by a length prefixed value, as in TLV encodings
+/// let mut record = (any(), length_prefixed(be_u16(), take_while(|b| b != 0)));
+/// assert_eq!(
+///     record.parse(&b"\x01\x00\x03abcrest"[..]),
+///     Ok(((1, &b"abc"[..]), &b"rest"[..]))
+/// );
+/// // `body` must consume the whole region
+/// assert!(record.parse(&b"\x01\x00\x03a\x00c"[..]).is_err());
+/// // and the region must be present in full
+/// assert!(record.parse(&b"\x01\x00\x03ab"[..]).is_err());
+/// # }
+/// ```
+pub fn length_prefixed<Input, L, P>(len: L, mut body: P) -> impl Parser<Input, Output = P::Output>
+where
+    Input: RangeStream + Clone,
+    Input::Range: StreamRange,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+    L: Parser<Input>,
+    L::Output: TryInto<usize>,
+    P: Parser<Region<Input>>,
+{
+    // Copies the stream as it is before the region is taken so that `body` can run on it
+    let snapshot = || parser(|input: &mut Input| Ok((input.clone(), Commit::Peek(()))));
+    len.and_then(|n: L::Output| {
+        n.try_into()
+            .map_err(|_| StreamErrorFor::<Input>::message_static_message("length is too large"))
+    })
+    .then(move |n| (snapshot(), take(n).map(move |_| n)))
+    .flat_map(move |(start, n)| {
+        let mut region = Region::new(start, n);
+        match body.parse_stream(&mut region).into_result() {
+            Ok((output, _)) if region.remaining() == 0 => Ok(output),
+            Ok(_) => Err(Input::Error::from_error(
+                region.position(),
+                StreamErrorFor::<Input>::message_format(format_args!(
+                    "length prefixed body left {} elements unparsed",
+                    region.remaining()
+                )),
+            )),
+            Err(err) => Err(err.into_inner().error),
+        }
+    })
+}
+
 pub struct TakeWhile<Input, F>(F, PhantomData<fn(Input) -> Input>);
 impl<Input, F> Parser<Input> for TakeWhile<Input, F>
 where
@@ -631,7 +771,13 @@ where
 #[cfg(test)]
 mod tests {
 
-    use crate::Parser;
+    use crate::{
+        parser::{
+            char::{char, digit, letter},
+            repeat::skip_many1,
+        },
+        Parser,
+    };
 
     use super::*;
 
@@ -651,6 +797,162 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn recognize_borrows_from_input() {
+        let input = "ident rest";
+        let (ident, rest) = recognize(skip_many1(letter())).parse(input).unwrap();
+        assert_eq!((ident, rest), ("ident", " rest"));
+        assert_eq!(ident.as_ptr(), input.as_ptr());
+
+        let mut number = recognize((skip_many1(digit()), char('.'), skip_many1(digit())));
+        assert_eq!(number.parse("12.5rest"), Ok(("12.5", "rest")));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn length_prefixed_errors() {
+        use crate::{
+            parser::{byte::byte, repeat::count_min_max, token::any},
+            stream::{
+                easy::{Error, Errors},
+                position,
+            },
+            EasyParser,
+        };
+
+        let mut pairs = length_prefixed(any(), count_min_max::<Vec<_>, _, _>(1, 2, take(2)));
+        assert_eq!(
+            pairs
+                .easy_parse(position::Stream::new(&b"\x04abcd!"[..]))
+                .map(|(output, rest)| (output, rest.input)),
+            Ok((vec![&b"ab"[..], &b"cd"[..]], &b"!"[..]))
+        );
+        assert_eq!(
+            pairs.easy_parse(position::Stream::new(&b"\x05abcde"[..])),
+            Err(Errors {
+                position: 5,
+                errors: vec![Error::Message(
+                    "length prefixed body left 1 elements unparsed"
+                        .to_string()
+                        .into()
+                )],
+            })
+        );
+        assert_eq!(
+            pairs.easy_parse(position::Stream::new(&b"\x01a"[..])),
+            Err(Errors {
+                position: 1,
+                errors: vec![
+                    Error::Message("expected 1 more elements".to_string().into()),
+                    Error::Unexpected(b'a'.into()),
+                ],
+            })
+        );
+
+        // Errors from the body point into the region
+        let mut tagged = length_prefixed(any(), (take(2), byte(b'!')));
+        assert_eq!(
+            tagged.easy_parse(position::Stream::new(&b"\x03ab?"[..])),
+            Err(Errors {
+                position: 3,
+                errors: vec![Error::Unexpected(b'?'.into()), Error::Expected(b'!'.into())],
+            })
+        );
+        assert_eq!(
+            tagged.easy_parse(position::Stream::new(&b"\x02ab!"[..])),
+            Err(Errors {
+                position: 3,
+                errors: vec![Error::end_of_input(), Error::Expected(b'!'.into())],
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn checksum_mismatch_is_reported_at_the_region() {
+        use crate::{
+            parser::{byte::byte, token::any},
+            stream::{
+                easy::{Error, Errors},
+                position,
+            },
+            EasyParser,
+        };
+
+        let xor = |region: &[u8]| region.iter().fold(0, |acc, b| acc ^ b);
+        let mut record = (byte(b'>'), checksummed(take(3), any(), xor));
+        assert_eq!(
+            record
+                .easy_parse(position::Stream::new(&b">\x01\x02\x04\x07"[..]))
+                .map(|(output, rest)| (output, rest.input)),
+            Ok(((b'>', &b"\x01\x02\x04"[..]), &b""[..]))
+        );
+        assert_eq!(
+            record.easy_parse(position::Stream::new(&b">\x01\x02\x04\x00"[..])),
+            Err(Errors {
+                position: 1,
+                errors: vec![Error::Message("checksum mismatch".into())],
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn recognize_through_stream_wrappers() {
+        use crate::{
+            parser::{byte, char::alpha_num, repeat::skip_many},
+            stream::position,
+            EasyParser,
+        };
+
+        let mut ident = recognize((letter(), skip_many(alpha_num())));
+        assert_eq!(
+            ident
+                .easy_parse(position::Stream::new("x1 = 2"))
+                .map(|(ident, rest)| (ident, rest.input)),
+            Ok(("x1", " = 2"))
+        );
+
+        let mut ident = recognize((byte::letter(), skip_many(byte::alpha_num())));
+        assert_eq!(ident.parse(&b"abc1+"[..]), Ok((&b"abc1"[..], &b"+"[..])));
+    }
+
+    #[test]
+    fn take_while_borrows_from_input() {
+        let input = "abc123";
+        let (letters, rest) = take_while(|c: char| c.is_alphabetic())
+            .parse(input)
+            .unwrap();
+        assert_eq!((letters, rest), ("abc", "123"));
+        assert_eq!(letters.as_ptr(), input.as_ptr());
+
+        let input = [1u32, 2, 30, 4];
+        let (small, rest) = take_while1(|x: u32| x < 10).parse(&input[..]).unwrap();
+        assert_eq!((small, rest), (&input[..2], &input[2..]));
+        assert_eq!(small.as_ptr(), input.as_ptr());
+
+        assert_eq!(
+            take_while(|c: char| c.is_digit(10)).parse("abc"),
+            Ok(("", "abc"))
+        );
+        assert!(take_while1(|c: char| c.is_digit(10)).parse("abc").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn take_reports_available_elements_of_partial_input() {
+        use crate::stream::{easy, PartialStream};
+
+        let err = take(4)
+            .parse(easy::Stream(PartialStream(&b"ab"[..])))
+            .unwrap_err();
+        assert!(err.errors.contains(&easy::Error::Message(
+            "expected 4 elements but only 2 were available"
+                .to_string()
+                .into()
+        )));
+    }
+
     #[test]
     fn range_string_no_char_boundary_error() {
         let mut parser = range("hello");
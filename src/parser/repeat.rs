@@ -11,7 +11,7 @@ use crate::{
         choice::{optional, Optional, Or},
         combinator::{ignore, Ignore},
         function::{parser, FnParser},
-        sequence::With,
+        sequence::{Skip, With},
         token::{value, Value},
         FirstMode, ParseMode,
     },
@@ -24,6 +24,9 @@ pub struct Count;
 
 /// Parses `parser` from zero up to `count` times.
 ///
+/// Use [`count_min_max(count, count, parser)`][count_min_max] if exactly `count` repetitions
+/// are required.
+///
 /// ```
 /// # extern crate combine;
 /// # use combine::*;
@@ -52,6 +55,9 @@ parser! {
     type PartialState = <With<Count<Sink, Input, P>, Value<Input, ()>> as Parser<Input>>::PartialState;
     /// Parses `parser` from zero up to `count` times skipping the output of `parser`.
     ///
+    /// Use [`skip_count_min_max(count, count, parser)`][skip_count_min_max] if exactly `count`
+    /// repetitions are required.
+    ///
     /// ```
     /// # extern crate combine;
     /// # use combine::*;
@@ -113,7 +119,19 @@ where
                 "expected {} more elements",
                 self.min - *count
             ));
-            iter.fail(err)
+            match iter.state {
+                // The element parser failed after earlier elements committed, so the errors which
+                // would normally be added through `add_error` must be added here
+                State::PeekErr(_) if iter.committed => {
+                    let mut err = Tracked::from(Input::Error::from_error(input.position(), err));
+                    if let Ok(t) = input.uncons() {
+                        err.error.add(StreamError::unexpected_token(t));
+                    }
+                    self.parser.add_error(&mut err);
+                    CommitErr(err.error)
+                }
+                _ => iter.fail(err),
+            }
         } else {
             iter.into_result_fast(elements).map(|x| {
                 *count = 0;
@@ -204,11 +222,12 @@ where
     state: State<<Input as StreamOnce>::Error>,
     partial_state: S,
     mode: M,
+    stop_on_empty_success: bool,
 }
 
 enum State<E> {
     Ok,
-    PeekErr,
+    PeekErr(Tracked<E>),
     CommitErr(E),
 }
 
@@ -226,17 +245,51 @@ where
             state: State::Ok,
             partial_state,
             mode,
+            stop_on_empty_success: false,
         }
     }
+
+    /// Makes the iterator stop, instead of looping forever, if the parser succeeds without
+    /// consuming any input. The value from that parse is discarded.
+    fn stop_on_empty_success(mut self) -> Self {
+        self.stop_on_empty_success = true;
+        self
+    }
+
     /// Converts the iterator to a `ParseResult`, returning `Ok` if the parsing so far has be done
     /// without any errors which committed data.
     pub fn into_result<O>(self, value: O) -> StdParseResult<O, Input> {
         self.into_result_(value).into()
     }
 
+    /// Returns the error which stopped the iterator unless it stopped at the end of the input.
+    ///
+    /// Unlike `into_result` this also returns the error of a parser which failed without
+    /// committing any input, which lets a parse error in the middle of the input be told apart
+    /// from the input running out.
+    pub fn into_error(mut self) -> Option<Input::Error> {
+        let mut err = match self.state {
+            State::Ok => return None,
+            State::PeekErr(err) => err,
+            State::CommitErr(err) => return Some(err),
+        };
+
+        let before = self.input.checkpoint();
+        match self.input.uncons() {
+            Ok(t) => err.error.add(StreamError::unexpected_token(t)),
+            Err(ref err) if err.is_unexpected_end_of_input() => return None,
+            Err(_) => (),
+        }
+        if let Err(err) = self.input.reset(before) {
+            return Some(err);
+        }
+        self.parser.add_error(&mut err);
+        Some(err.error)
+    }
+
     fn into_result_<O>(self, value: O) -> ParseResult<O, Input::Error> {
         match self.state {
-            State::Ok | State::PeekErr => {
+            State::Ok | State::PeekErr(_) => {
                 if self.committed {
                     CommitOk(value)
                 } else {
@@ -252,7 +305,7 @@ where
         O: Default,
     {
         match self.state {
-            State::Ok | State::PeekErr => {
+            State::Ok | State::PeekErr(_) => {
                 let value = mem::replace(value, O::default());
                 if self.committed {
                     CommitOk(value)
@@ -273,7 +326,7 @@ where
         >>::StreamError,
     ) -> ParseResult<T, Input::Error> {
         match self.state {
-            State::Ok | State::PeekErr => {
+            State::Ok | State::PeekErr(_) => {
                 let err = <Input as StreamOnce>::Error::from_error(self.input.position(), err);
                 if self.committed {
                     CommitErr(err)
@@ -304,6 +357,7 @@ where
             .parser
             .parse_mode(self.mode, self.input, self.partial_state.borrow_mut())
         {
+            PeekOk(_) if self.stop_on_empty_success && self.mode.is_first() => None,
             PeekOk(v) => {
                 self.mode.set_first();
                 Some(v)
@@ -313,12 +367,11 @@ where
                 self.committed = true;
                 Some(v)
             }
-            PeekErr(_) => {
+            PeekErr(err) => {
                 self.state = match self.input.reset(before) {
                     Err(err) => State::CommitErr(err),
-                    Ok(_) => State::PeekErr,
+                    Ok(_) => State::PeekErr(err),
                 };
-                self.state = State::PeekErr;
                 None
             }
             CommitErr(e) => {
@@ -355,7 +408,9 @@ where
         // TODO
         let (ref mut elements, ref mut child_state) = *state;
 
-        let mut iter = (&mut self.0).partial_iter(mode, input, child_state);
+        let mut iter = (&mut self.0)
+            .partial_iter(mode, input, child_state)
+            .stop_on_empty_success();
         elements.extend(iter.by_ref());
         iter.into_result_fast(elements)
     }
@@ -379,8 +434,8 @@ where
 /// annotating the resulting type binding `let collection: Vec<_> = ...` or by specializing when
 /// calling many, `many::<Vec<_>, _, _>(...)`.
 ///
-/// NOTE: If `p` can succeed without consuming any input this may hang forever as `many` will
-/// repeatedly use `p` to parse the same location in the input every time
+/// NOTE: If `p` succeeds without consuming any input `many` stops and the value from that
+/// parse is discarded, as `p` would otherwise succeed at the same location in the input forever
 ///
 /// ```
 /// # extern crate combine;
@@ -445,6 +500,7 @@ where
             state: State::Ok,
             partial_state: child_state,
             mode,
+            stop_on_empty_success: true,
         };
         elements.extend(iter.by_ref());
 
@@ -467,8 +523,9 @@ where
 /// annotating the resulting type binding `let collection: Vec<_> = ...` or by specializing when
 /// calling many1 `many1::<Vec<_>, _>(...)`.
 ///
-/// NOTE: If `p` can succeed without consuming any input this may hang forever as `many1` will
-/// repeatedly use `p` to parse the same location in the input every time
+/// NOTE: If `p` succeeds without consuming any input after the first element `many1` stops and
+/// the value from that parse is discarded, as `p` would otherwise succeed at the same location in
+/// the input forever
 ///
 ///
 /// ```
@@ -490,6 +547,185 @@ where
     Many1(p, PhantomData)
 }
 
+#[derive(Copy, Clone)]
+pub struct FoldMany<P, A, F> {
+    parser: P,
+    init: A,
+    f: F,
+}
+
+impl<Input, P, A, F> Parser<Input> for FoldMany<P, A, F>
+where
+    Input: Stream,
+    P: Parser<Input>,
+    A: Clone,
+    F: FnMut(A, P::Output) -> A,
+{
+    type Output = A;
+    type PartialState = (Option<A>, P::PartialState);
+
+    parse_mode!(Input);
+    #[inline]
+    fn parse_mode_impl<M>(
+        &mut self,
+        mode: M,
+        input: &mut Input,
+        state: &mut Self::PartialState,
+    ) -> ParseResult<Self::Output, Input::Error>
+    where
+        M: ParseMode,
+    {
+        let (ref mut acc, ref mut child_state) = *state;
+
+        if mode.is_first() || acc.is_none() {
+            *acc = Some(self.init.clone());
+        }
+
+        let f = &mut self.f;
+        let mut iter = (&mut self.parser)
+            .partial_iter(mode, input, child_state)
+            .stop_on_empty_success();
+        for x in iter.by_ref() {
+            *acc = acc.take().map(|acc| f(acc, x));
+        }
+        iter.into_result_(())
+            .map(|()| acc.take().expect("accumulator"))
+    }
+
+    fn add_error(&mut self, errors: &mut Tracked<<Input as StreamOnce>::Error>) {
+        self.parser.add_error(errors)
+    }
+
+    fn add_committed_expected_error(&mut self, errors: &mut Tracked<<Input as StreamOnce>::Error>) {
+        self.add_error(errors);
+    }
+
+    fn parser_count(&self) -> ErrorOffset {
+        self.parser.parser_count()
+    }
+}
+
+/// Parses `parser` zero or more times, combining each output with an accumulator (starting at
+/// `init`) using `f`. Unlike [`many`] no collection is built.
+///
+/// NOTE: If `parser` succeeds without consuming any input `fold_many` stops and the value from
+/// that parse is discarded, as `parser` would otherwise succeed at the same location in the input
+/// forever
+///
+/// ```
+/// # extern crate combine;
+/// # use combine::*;
+/// # use combine::parser::char::digit;
+/// # use combine::parser::repeat::fold_many;
+/// # fn main() {
+/// let mut parser = fold_many(digit(), 0u32, |acc, d| acc * 10 + d.to_digit(10).unwrap());
+/// assert_eq!(parser.parse("123A"), Ok((123, "A")));
+/// assert_eq!(parser.parse("A"), Ok((0, "A")));
+/// # }
+/// ```
+///
+/// [`many`]: fn.many.html
+pub fn fold_many<Input, P, A, F>(parser: P, init: A, f: F) -> FoldMany<P, A, F>
+where
+    Input: Stream,
+    P: Parser<Input>,
+    A: Clone,
+    F: FnMut(A, P::Output) -> A,
+{
+    FoldMany { parser, init, f }
+}
+
+#[derive(Copy, Clone)]
+pub struct FoldMany1<P, A, F> {
+    parser: P,
+    init: A,
+    f: F,
+}
+
+impl<Input, P, A, F> Parser<Input> for FoldMany1<P, A, F>
+where
+    Input: Stream,
+    P: Parser<Input>,
+    A: Clone,
+    F: FnMut(A, P::Output) -> A,
+{
+    type Output = A;
+    type PartialState = (Option<A>, bool, P::PartialState);
+
+    parse_mode!(Input);
+    #[inline]
+    fn parse_mode_impl<M>(
+        &mut self,
+        mut mode: M,
+        input: &mut Input,
+        state: &mut Self::PartialState,
+    ) -> ParseResult<Self::Output, Input::Error>
+    where
+        M: ParseMode,
+    {
+        let (ref mut acc, ref mut committed_state, ref mut child_state) = *state;
+
+        if mode.is_first() || acc.is_none() {
+            let (first, committed) = ctry!(self.parser.parse_mode(mode, input, child_state));
+            *acc = Some((self.f)(self.init.clone(), first));
+            *committed_state = !committed.is_peek();
+            mode.set_first();
+        }
+
+        let f = &mut self.f;
+        let mut iter = Iter {
+            parser: &mut self.parser,
+            committed: *committed_state,
+            input,
+            state: State::Ok,
+            partial_state: child_state,
+            mode,
+            stop_on_empty_success: true,
+        };
+        for x in iter.by_ref() {
+            *acc = acc.take().map(|acc| f(acc, x));
+        }
+        iter.into_result_(())
+            .map(|()| acc.take().expect("accumulator"))
+    }
+
+    fn add_committed_expected_error(&mut self, errors: &mut Tracked<<Input as StreamOnce>::Error>) {
+        self.add_error(errors);
+    }
+
+    forward_parser!(Input, add_error parser_count, parser);
+}
+
+/// Parses `parser` one or more times, combining each output with an accumulator (starting at
+/// `init`) using `f`. Unlike [`many1`] no collection is built.
+///
+/// NOTE: If `parser` succeeds without consuming any input after the first element `fold_many1`
+/// stops and the value from that parse is discarded, as `parser` would otherwise succeed at the
+/// same location in the input forever
+///
+/// ```
+/// # extern crate combine;
+/// # use combine::*;
+/// # use combine::parser::char::digit;
+/// # use combine::parser::repeat::fold_many1;
+/// # fn main() {
+/// let mut parser = fold_many1(digit(), 0u32, |acc, d| acc * 10 + d.to_digit(10).unwrap());
+/// assert_eq!(parser.parse("123A"), Ok((123, "A")));
+/// assert!(parser.parse("A").is_err());
+/// # }
+/// ```
+///
+/// [`many1`]: fn.many1.html
+pub fn fold_many1<Input, P, A, F>(parser: P, init: A, f: F) -> FoldMany1<P, A, F>
+where
+    Input: Stream,
+    P: Parser<Input>,
+    A: Clone,
+    F: FnMut(A, P::Output) -> A,
+{
+    FoldMany1 { parser, init, f }
+}
+
 #[derive(Clone)]
 #[doc(hidden)]
 // FIXME Should not be public
@@ -515,8 +751,8 @@ parser! {
     type PartialState = <Ignore<Many<Sink, Ignore<P>>> as Parser<Input>>::PartialState;
 /// Parses `p` zero or more times ignoring the result.
 ///
-/// NOTE: If `p` can succeed without consuming any input this may hang forever as `skip_many` will
-/// repeatedly use `p` to parse the same location in the input every time
+/// NOTE: If `p` succeeds without consuming any input `skip_many` stops and the value from that
+/// parse is discarded, as `p` would otherwise succeed at the same location in the input forever
 ///
 /// ```
 /// # extern crate combine;
@@ -542,8 +778,9 @@ parser! {
     type PartialState = <Ignore<Many1<Sink, Ignore<P>>> as Parser<Input>>::PartialState;
 /// Parses `p` one or more times ignoring the result.
 ///
-/// NOTE: If `p` can succeed without consuming any input this may hang forever as `skip_many1` will
-/// repeatedly use `p` to parse the same location in the input every time
+/// NOTE: If `p` succeeds without consuming any input after the first element `skip_many1` stops and
+/// the value from that parse is discarded, as `p` would otherwise succeed at the same location in
+/// the input forever
 ///
 /// ```
 /// # extern crate combine;
@@ -613,6 +850,10 @@ where
 /// annotating the resulting type binding `let collection: Vec<_> = ...` or by specializing when
 /// calling `sep_by`, `sep_by::<Vec<_>, _, _>(...)`.
 ///
+/// NOTE: If `separator` and `parser` both succeed without consuming any input `sep_by` stops and
+/// the value from that parse is discarded, as they would otherwise succeed at the same location in
+/// the input forever
+///
 /// ```
 /// # extern crate combine;
 /// # use combine::*;
@@ -686,7 +927,7 @@ where
 
         rest.combine_commit(move |_| {
             let rest = (&mut self.separator).with(&mut self.parser);
-            let mut iter = Iter::new(rest, mode, input, child_state);
+            let mut iter = Iter::new(rest, mode, input, child_state).stop_on_empty_success();
 
             elements.extend(iter.by_ref());
 
@@ -711,6 +952,10 @@ where
 /// annotating the resulting type binding `let collection: Vec<_> = ...` or by specializing when
 /// calling `sep_by`, `sep_by1::<Vec<_>, _, _>(...)`.
 ///
+/// NOTE: If `separator` and `parser` both succeed without consuming any input `sep_by1` stops and
+/// the value from that parse is discarded, as they would otherwise succeed at the same location in
+/// the input forever
+///
 /// ```
 /// # extern crate combine;
 /// # use combine::*;
@@ -794,6 +1039,10 @@ where
 /// annotating the resulting type binding `let collection: Vec<_> = ...` or by specializing when
 /// calling `sep_by`, `sep_by::<Vec<_>, _, _>(...)`
 ///
+/// NOTE: If `separator` and `parser` both succeed without consuming any input `sep_end_by` stops
+/// and the value from that parse is discarded, as they would otherwise succeed at the same location
+/// in the input forever
+///
 /// ```
 /// # extern crate combine;
 /// # use combine::*;
@@ -804,6 +1053,9 @@ where
 /// assert_eq!(result_ok, Ok((vec!['1', '2', '3'], "")));
 /// let result_ok2 = parser.parse("1;2;3");
 /// assert_eq!(result_ok2, Ok((vec!['1', '2', '3'], "")));
+/// // A separator is consumed even if it isn't followed by another element
+/// let result_ok3 = parser.parse("1;2;x");
+/// assert_eq!(result_ok3, Ok((vec!['1', '2'], "x")));
 /// # }
 /// ```
 pub fn sep_end_by<F, Input, P, S>(parser: P, separator: S) -> SepEndBy<F, P, S>
@@ -869,7 +1121,7 @@ where
 
         rest.combine_commit(|_| {
             let rest = (&mut self.separator).with(optional(&mut self.parser));
-            let mut iter = Iter::new(rest, mode, input, child_state);
+            let mut iter = Iter::new(rest, mode, input, child_state).stop_on_empty_success();
 
             // Parse elements until `self.parser` returns `None`
             elements.extend(iter.by_ref().scan((), |_, x| x));
@@ -897,6 +1149,10 @@ where
 /// supplied, either by annotating the resulting type binding `let collection: Vec<_> = ...` or by
 /// specializing when calling `sep_by`, `sep_by1::<Vec<_>, _, _>(...)`.
 ///
+/// NOTE: If `separator` and `parser` both succeed without consuming any input `sep_end_by1` stops
+/// and the value from that parse is discarded, as they would otherwise succeed at the same location
+/// in the input forever
+///
 /// ```
 /// # extern crate combine;
 /// # use combine::*;
@@ -932,6 +1188,72 @@ where
     }
 }
 
+parser! {
+    pub struct EndBy;
+    type PartialState = <Many<F, Skip<P, S>> as Parser<Input>>::PartialState;
+/// Parses `parser` zero or more times where every element must be followed by `separator`,
+/// returning a collection with the values from `parser`.
+///
+/// If the returned collection cannot be inferred type annotations must be supplied, either by
+/// annotating the resulting type binding `let collection: Vec<_> = ...` or by specializing when
+/// calling `end_by`, `end_by::<Vec<_>, _, _, _>(...)`
+///
+/// ```
+/// # extern crate combine;
+/// # use combine::*;
+/// # use combine::parser::char::digit;
+/// # fn main() {
+/// let mut parser = end_by(digit(), token(';'));
+/// let result_ok = parser.parse("1;2;3;");
+/// assert_eq!(result_ok, Ok((vec!['1', '2', '3'], "")));
+/// let result_err = parser.parse("1;2;3");
+/// assert!(result_err.is_err());
+/// # }
+/// ```
+pub fn end_by[F, Input, P, S](parser: P, separator: S)(Input) -> F
+where [
+    F: Extend<P::Output> + Default,
+    P: Parser<Input>,
+    S: Parser<Input>,
+]
+{
+    many(parser.skip(separator))
+}
+}
+
+parser! {
+    pub struct EndBy1;
+    type PartialState = <Many1<F, Skip<P, S>> as Parser<Input>>::PartialState;
+/// Parses `parser` one or more times where every element must be followed by `separator`,
+/// returning a collection with the values from `parser`.
+///
+/// If the returned collection cannot be inferred type annotations must be supplied, either by
+/// annotating the resulting type binding `let collection: Vec<_> = ...` or by specializing when
+/// calling `end_by1`, `end_by1::<Vec<_>, _, _, _>(...)`
+///
+/// ```
+/// # extern crate combine;
+/// # use combine::*;
+/// # use combine::parser::char::digit;
+/// # fn main() {
+/// let mut parser = end_by1(digit(), token(';'));
+/// let result_ok = parser.parse("1;2;3;");
+/// assert_eq!(result_ok, Ok((vec!['1', '2', '3'], "")));
+/// let result_err = parser.parse("");
+/// assert!(result_err.is_err());
+/// # }
+/// ```
+pub fn end_by1[F, Input, P, S](parser: P, separator: S)(Input) -> F
+where [
+    F: Extend<P::Output> + Default,
+    P: Parser<Input>,
+    S: Parser<Input>,
+]
+{
+    many1(parser.skip(separator))
+}
+}
+
 #[derive(Copy, Clone)]
 pub struct Chainl1<P, Op>(P, Op);
 impl<Input, P, Op> Parser<Input> for Chainl1<P, Op>
@@ -1039,27 +1361,33 @@ where
         let (mut l, mut committed) = ctry!(self.0.parse_lazy(input));
         loop {
             let before = input.checkpoint();
-            let op = match self.1.parse_lazy(input).into() {
-                Ok((x, rest)) => {
-                    committed = committed.merge(rest);
-                    x
-                }
+            let (op, op_committed) = match self.1.parse_lazy(input).into() {
+                Ok((x, rest)) => (x, rest),
                 Err(Commit::Commit(err)) => return CommitErr(err.error),
                 Err(Commit::Peek(_)) => {
                     ctry!(input.reset(before).committed());
                     break;
                 }
             };
-            let before = input.checkpoint();
+            let before_r = input.checkpoint();
             match self.parse_lazy(input).into() {
                 Ok((r, rest)) => {
                     l = op(l, r);
-                    committed = committed.merge(rest);
+                    committed = committed.merge(op_committed).merge(rest);
                 }
                 Err(Commit::Commit(err)) => return CommitErr(err.error),
-                Err(Commit::Peek(_)) => {
-                    ctry!(input.reset(before).committed());
-                    break;
+                Err(Commit::Peek(mut err)) => {
+                    if op_committed.is_peek() {
+                        ctry!(input.reset(before).committed());
+                        break;
+                    }
+                    // `op` has already committed so the missing right operand is an error
+                    ctry!(input.reset(before_r).committed());
+                    if let Ok(t) = input.uncons() {
+                        err.error.add(StreamError::unexpected_token(t));
+                    }
+                    self.0.add_error(&mut err);
+                    return CommitErr(err.error);
                 }
             }
         }
@@ -1081,8 +1409,8 @@ where
 /// let number = digit().map(|c: char| c.to_digit(10).unwrap());
 /// let pow = token('^').map(|_| |l: u32, r: u32| l.pow(r));
 /// let mut parser = chainr1(number, pow);
-///     assert_eq!(parser.parse("2^3^2"), Ok((512, "")));
-/// }
+/// assert_eq!(parser.parse("2^3^2"), Ok((512, "")));
+/// # }
 /// ```
 pub fn chainr1<Input, P, Op>(parser: P, op: Op) -> Chainr1<P, Op>
 where
@@ -1327,6 +1655,189 @@ parser! {
     }
 }
 
+#[derive(Copy, Clone)]
+pub struct ManyTill<F, P, E> {
+    parser: P,
+    end: E,
+    _marker: PhantomData<fn() -> F>,
+}
+impl<F, P, E> ManyTill<F, P, E> {
+    fn parse_till<Input, M>(
+        &mut self,
+        mut mode: M,
+        input: &mut Input,
+        state: &mut (F, bool, P::PartialState, E::PartialState),
+    ) -> ParseResult<(F, E::Output), Input::Error>
+    where
+        Input: Stream,
+        F: Extend<P::Output> + Default,
+        P: Parser<Input>,
+        E: Parser<Input>,
+        M: ParseMode,
+    {
+        let (output, is_parse, parse_state, end_state) = state;
+
+        let mut committed = false;
+        loop {
+            let before = input.checkpoint();
+            if *is_parse {
+                match self.parser.parse_mode(mode, input, parse_state) {
+                    CommitOk(x) => {
+                        output.extend(Some(x));
+                        committed = true;
+                    }
+                    PeekOk(_) if mode.is_first() => {
+                        // `end` has already failed at this position so applying `parser` again
+                        // would succeed at the same location in the input forever
+                        let mut err = Tracked::from(Input::Error::empty(input.position()));
+                        ctry!(input.reset(before.clone()).committed());
+                        if let Ok(t) = input.uncons() {
+                            ctry!(input.reset(before).committed());
+                            err.error.add(StreamError::unexpected_token(t));
+                        }
+                        if !committed {
+                            return PeekErr(err);
+                        }
+                        self.end.add_error(&mut err);
+                        self.parser.add_error(&mut err);
+                        return CommitErr(err.error);
+                    }
+                    PeekOk(x) => output.extend(Some(x)),
+                    CommitErr(err) => return CommitErr(err),
+                    PeekErr(mut err) => {
+                        if !committed {
+                            return PeekErr(err);
+                        }
+                        // Earlier elements committed so the errors which would normally be added
+                        // through `add_error` must be added here
+                        ctry!(input.reset(before.clone()).committed());
+                        if let Ok(t) = input.uncons() {
+                            ctry!(input.reset(before).committed());
+                            err.error.add(StreamError::unexpected_token(t));
+                        }
+                        self.end.add_error(&mut err);
+                        self.parser.add_error(&mut err);
+                        return CommitErr(err.error);
+                    }
+                }
+                *is_parse = false;
+            } else {
+                match self.end.parse_mode(mode, input, end_state) {
+                    CommitOk(end) => return CommitOk((mem::take(output), end)),
+                    PeekOk(end) => {
+                        let output = mem::take(output);
+                        return if committed {
+                            CommitOk((output, end))
+                        } else {
+                            PeekOk((output, end))
+                        };
+                    }
+                    CommitErr(err) => return CommitErr(err),
+                    PeekErr(_) => {
+                        ctry!(input.reset(before).committed());
+                        mode.set_first();
+                        *is_parse = true;
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<F, Input, P, E> Parser<Input> for ManyTill<F, P, E>
+where
+    Input: Stream,
+    F: Extend<P::Output> + Default,
+    P: Parser<Input>,
+    E: Parser<Input>,
+{
+    type Output = (F, E::Output);
+    type PartialState = (F, bool, P::PartialState, E::PartialState);
+
+    parse_mode!(Input);
+    #[inline]
+    fn parse_mode_impl<M>(
+        &mut self,
+        mode: M,
+        input: &mut Input,
+        state: &mut Self::PartialState,
+    ) -> ParseResult<Self::Output, Input::Error>
+    where
+        M: ParseMode,
+    {
+        let (output, is_parse, _, _) = &mut *state;
+        if mode.is_first() {
+            *output = F::default();
+            *is_parse = false;
+        }
+
+        let result = self.parse_till(mode, input, state);
+        if result.is_err() && !input.is_partial() {
+            // Only a partial input can resume from the state so nothing collected before the
+            // error may leak into the next parse
+            state.0 = F::default();
+            state.1 = false;
+        }
+        result
+    }
+
+    fn add_error(&mut self, errors: &mut Tracked<<Input as StreamOnce>::Error>) {
+        let before = errors.offset.0;
+        if errors.offset != ErrorOffset(0) {
+            // Both `end` and `parser` are tried at the same position so both may be expected
+            errors.offset = ErrorOffset(1);
+            self.end.add_error(errors);
+            errors.offset = ErrorOffset(1);
+            self.parser.add_error(errors);
+        }
+        errors.offset.0 = before.saturating_sub(1);
+    }
+}
+
+/// Parses `parser` zero or more times until `end` succeeds, returning the collected outputs of
+/// `parser` together with the output of `end`. Unlike [`repeat_until`] the input matched by `end`
+/// is consumed.
+///
+/// `end` is tried before each application of `parser`, so `attempt` may be needed if `end` can
+/// commit input before failing.
+///
+/// NOTE: If `parser` succeeds without consuming any input where `end` failed `many_till` returns
+/// an error, as `parser` would otherwise succeed at the same location in the input forever
+///
+/// ```
+/// # extern crate combine;
+/// # use combine::*;
+/// # use combine::parser::char::{letter, string};
+/// # use combine::parser::repeat::many_till;
+/// # use combine::parser::token::any;
+/// # fn main() {
+/// let mut comment = string("/*").with(many_till::<String, _, _, _>(any(), attempt(string("*/"))));
+/// assert_eq!(
+///     comment.parse("/* a * b */ rest"),
+///     Ok(((" a * b ".to_string(), "*/"), " rest"))
+/// );
+/// assert!(comment.parse("/* unclosed").is_err());
+///
+/// let mut word = many_till::<Vec<_>, _, _, _>(letter(), token(';'));
+/// assert_eq!(word.parse("ab;c"), Ok(((vec!['a', 'b'], ';'), "c")));
+/// # }
+/// ```
+///
+/// [`repeat_until`]: fn.repeat_until.html
+pub fn many_till<F, Input, P, E>(parser: P, end: E) -> ManyTill<F, P, E>
+where
+    Input: Stream,
+    F: Extend<P::Output> + Default,
+    P: Parser<Input>,
+    E: Parser<Input>,
+{
+    ManyTill {
+        parser,
+        end,
+        _marker: PhantomData,
+    }
+}
+
 #[derive(Default)]
 pub struct EscapedState<T, U>(PhantomData<(T, U)>);
 
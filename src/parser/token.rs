@@ -193,16 +193,27 @@ where
     }
 }
 
-/// Parses a character and succeeds if the character is equal to `c`.
+/// Parses a token and succeeds if the token is equal to `c`.
+///
+/// Works on any stream whose tokens implement `PartialEq`, not only streams of characters.
 ///
 /// ```
 /// # extern crate combine;
 /// # use combine::*;
+/// # use combine::stream::easy;
 /// # fn main() {
 /// let result = token('!')
 ///     .parse("!")
 ///     .map(|x| x.0);
 /// assert_eq!(result, Ok('!'));
+///
+/// let result = token(3)
+///     .easy_parse(&[1, 2][..])
+///     .map_err(|err| err.errors);
+/// assert_eq!(result, Err(vec![
+///     easy::Error::Unexpected(easy::Info::Token(1)),
+///     easy::Error::Expected(easy::Info::Token(3)),
+/// ]));
 /// # }
 /// ```
 pub fn token<Input>(c: Input::Token) -> Token<Input>
@@ -241,12 +252,14 @@ where
         let start = input.position();
         let mut committed = false;
         for c in self.tokens.clone() {
+            let position = input.position();
             match crate::stream::uncons(input) {
                 CommitOk(other) | PeekOk(other) => {
                     if !(self.cmp)(c, other.clone()) {
                         return if committed {
+                            // Point at the first token which did not match
                             let mut errors = <Input as StreamOnce>::Error::from_error(
-                                start,
+                                position,
                                 StreamError::unexpected_token(other),
                             );
                             errors.add_expected(&self.expected);
@@ -345,12 +358,14 @@ where
         let start = input.position();
         let mut committed = false;
         for c in self.tokens.clone() {
+            let position = input.position();
             match crate::stream::uncons(input) {
                 CommitOk(other) | PeekOk(other) => {
                     if !(self.cmp)(c, other.clone()) {
                         return if committed {
+                            // Point at the first token which did not match
                             let errors = <Input as StreamOnce>::Error::from_error(
-                                start,
+                                position,
                                 StreamError::unexpected_token(other),
                             );
                             CommitErr(errors)
@@ -733,6 +733,124 @@ where
     }
 }
 
+impl<'a> Errors<u8, &'a [u8], usize> {
+    /// Returns a value which displays the errors of a byte parser together with a hexdump of
+    /// `input` around the offset where the error occurred. Tokens and ranges in the errors are
+    /// shown as hexadecimal bytes.
+    ///
+    /// The position must be the offset into `input`. When parsing a plain `&[u8]` it can be
+    /// obtained with `err.map_position(|p| p.translate_position(input))`.
+    ///
+    /// ```
+    /// use combine::*;
+    /// use combine::parser::{byte::bytes, range::range};
+    /// use combine::stream::position;
+    ///
+    /// let input = &b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR\0\0\x01\0"[..];
+    /// let err = (bytes(b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR"), range(&b"\x01\0\0\0"[..]))
+    ///     .easy_parse(position::Stream::new(input))
+    ///     .unwrap_err();
+    /// assert_eq!(
+    ///     err.hexdump(input).to_string(),
+    ///     "\
+    /// Parse error at offset 0x10
+    /// 00000000  89 50 4e 47 0d 0a 1a 0a  00 00 00 0d 49 48 44 52  |.PNG........IHDR|
+    /// 00000010  00 00 01 00                                       |....|
+    ///           ^^
+    /// Unexpected `0x00`
+    /// Expected `0x01 0x00 0x00 0x00`
+    /// "
+    /// );
+    /// ```
+    pub fn hexdump(self, input: &'a [u8]) -> Hexdump<'a> {
+        Hexdump {
+            errors: self.map_token(HexByte).map_range(HexBytes),
+            input,
+        }
+    }
+}
+
+/// Displays a byte in hexadecimal.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HexByte(pub u8);
+
+impl fmt::Display for HexByte {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#04x}", self.0)
+    }
+}
+
+/// Displays a byte slice as space separated hexadecimal bytes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HexBytes<'a>(pub &'a [u8]);
+
+impl<'a> fmt::Display for HexBytes<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, byte) in self.0.iter().enumerate() {
+            if i != 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{:#04x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+/// Errors of a byte parser displayed with a hexdump of the input around the error.
+///
+/// Created by [`Errors::hexdump`](struct.Errors.html#method.hexdump).
+#[derive(Debug)]
+pub struct Hexdump<'a> {
+    errors: Errors<HexByte, HexBytes<'a>, usize>,
+    input: &'a [u8],
+}
+
+impl<'a> Hexdump<'a> {
+    const WIDTH: usize = 16;
+
+    fn fmt_line(&self, f: &mut fmt::Formatter<'_>, start: usize) -> fmt::Result {
+        write!(f, "{:08x} ", start)?;
+        for offset in start..start + Self::WIDTH {
+            if offset - start == Self::WIDTH / 2 {
+                write!(f, " ")?;
+            }
+            match self.input.get(offset) {
+                Some(byte) => write!(f, " {:02x}", byte)?,
+                None => write!(f, "   ")?,
+            }
+        }
+        write!(f, "  |")?;
+        for &byte in self.input.iter().skip(start).take(Self::WIDTH) {
+            let c = if byte.is_ascii_graphic() || byte == b' ' {
+                char::from(byte)
+            } else {
+                '.'
+            };
+            write!(f, "{}", c)?;
+        }
+        writeln!(f, "|")
+    }
+}
+
+impl<'a> fmt::Display for Hexdump<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let position = self.errors.position;
+        writeln!(f, "Parse error at offset {:#x}", position)?;
+
+        // Show the line containing the error and the line before it for context
+        let line = position - position % Self::WIDTH;
+        if line >= Self::WIDTH {
+            self.fmt_line(f, line - Self::WIDTH)?;
+        }
+        self.fmt_line(f, line)?;
+        let column = position % Self::WIDTH;
+        let indent = 10 + 3 * column + if column >= Self::WIDTH / 2 { 1 } else { 0 };
+        writeln!(f, "{:indent$}^^", "", indent = indent)?;
+
+        Error::fmt_errors(&self.errors.errors, f)
+    }
+}
+
 impl<T: fmt::Display, R: fmt::Display> fmt::Display for Error<T, R> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
@@ -1000,6 +1000,148 @@ where
     }
 }
 
+/// Stream which limits `S` to its next `len` tokens, returning the end of input after them.
+///
+/// Since the tokens, positions and errors of `S` are used as is, errors from parsers running on a
+/// `Region` point into the wrapped stream. See [`length_prefixed`].
+///
+/// [`length_prefixed`]: ../parser/range/fn.length_prefixed.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
+pub struct Region<S> {
+    stream: S,
+    remaining: usize,
+}
+
+impl<S> Region<S> {
+    /// Limits `stream` to its next `len` tokens.
+    pub fn new(stream: S, len: usize) -> Self {
+        Region {
+            stream,
+            remaining: len,
+        }
+    }
+
+    /// Returns the number of tokens left before the end of the region.
+    pub fn remaining(&self) -> usize {
+        self.remaining
+    }
+}
+
+impl<S> Positioned for Region<S>
+where
+    S: Positioned,
+{
+    #[inline]
+    fn position(&self) -> Self::Position {
+        self.stream.position()
+    }
+}
+
+impl<S> ResetStream for Region<S>
+where
+    S: ResetStream,
+{
+    type Checkpoint = (S::Checkpoint, usize);
+
+    #[inline]
+    fn checkpoint(&self) -> Self::Checkpoint {
+        (self.stream.checkpoint(), self.remaining)
+    }
+
+    #[inline]
+    fn reset(&mut self, checkpoint: Self::Checkpoint) -> Result<(), S::Error> {
+        self.stream.reset(checkpoint.0)?;
+        self.remaining = checkpoint.1;
+        Ok(())
+    }
+}
+
+impl<S> StreamOnce for Region<S>
+where
+    S: StreamOnce,
+{
+    type Token = S::Token;
+    type Range = S::Range;
+    type Position = S::Position;
+    type Error = S::Error;
+
+    #[inline]
+    fn uncons(&mut self) -> Result<S::Token, StreamErrorFor<Self>> {
+        if self.remaining == 0 {
+            return Err(StreamErrorFor::<Self>::end_of_input());
+        }
+        let token = self.stream.uncons()?;
+        self.remaining -= 1;
+        Ok(token)
+    }
+}
+
+impl<S> RangeStreamOnce for Region<S>
+where
+    S: RangeStreamOnce + Clone,
+{
+    #[inline]
+    fn uncons_range(&mut self, size: usize) -> Result<Self::Range, StreamErrorFor<Self>> {
+        if size > self.remaining {
+            return Err(StreamErrorFor::<Self>::end_of_input());
+        }
+        let range = self.stream.uncons_range(size)?;
+        self.remaining -= size;
+        Ok(range)
+    }
+
+    #[inline]
+    fn uncons_while<F>(&mut self, mut f: F) -> Result<Self::Range, StreamErrorFor<Self>>
+    where
+        F: FnMut(Self::Token) -> bool,
+    {
+        let (remaining, mut taken) = (self.remaining, 0);
+        let result = self.stream.uncons_while(|t| {
+            if taken < remaining && f(t) {
+                taken += 1;
+                true
+            } else {
+                false
+            }
+        });
+        self.remaining -= taken;
+        result
+    }
+
+    fn uncons_while1<F>(&mut self, mut f: F) -> ParseResult<Self::Range, StreamErrorFor<Self>>
+    where
+        F: FnMut(Self::Token) -> bool,
+    {
+        if self.remaining == 0 {
+            return PeekErr(Tracked::from(StreamErrorFor::<Self>::end_of_input()));
+        }
+        let (remaining, mut taken) = (self.remaining, 0);
+        let result = self.stream.uncons_while1(|t| {
+            if taken < remaining && f(t) {
+                taken += 1;
+                true
+            } else {
+                false
+            }
+        });
+        self.remaining -= taken;
+        result
+    }
+
+    #[inline]
+    fn distance(&self, end: &Self::Checkpoint) -> usize {
+        self.stream.distance(&end.0)
+    }
+
+    #[inline]
+    fn range(&self) -> Self::Range {
+        let mut stream = self.stream.clone();
+        stream
+            .uncons_range(self.remaining)
+            .unwrap_or_else(|_| self.stream.range())
+    }
+}
+
 /// Newtype for constructing a stream from a slice where the items in the slice are not copyable.
 #[derive(Copy, Eq, PartialEq, Ord, PartialOrd, Debug)]
 pub struct SliceStream<'a, T: 'a>(pub &'a [T]);
@@ -425,7 +425,11 @@ where
 #[cfg(all(feature = "std", test))]
 mod tests {
 
-    use crate::Parser;
+    use crate::{
+        parser::byte::byte,
+        stream::easy::{Error, Errors},
+        EasyParser, Parser,
+    };
 
     use super::*;
 
@@ -462,4 +466,27 @@ mod tests {
             ))
         );
     }
+
+    #[test]
+    fn byte_positions_after_error() {
+        let mut parser = (byte(b'a'), byte(b'\n'), byte(b'c'));
+        assert_eq!(
+            parser.easy_parse(Stream::new(&b"a\nx"[..])),
+            Err(Errors {
+                position: 2,
+                errors: vec![Error::Unexpected(b'x'.into()), Error::Expected(b'c'.into())],
+            })
+        );
+
+        let mut parser = (byte(b'a'), byte(b'\n'), byte(b'c'));
+        assert_eq!(
+            parser
+                .easy_parse(Stream::with_positioner(
+                    &b"a\nx"[..],
+                    SourcePosition::default()
+                ))
+                .map_err(|err| err.position),
+            Err(SourcePosition { line: 2, column: 1 })
+        );
+    }
 }
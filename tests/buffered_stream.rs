@@ -1,10 +1,12 @@
 #![cfg(feature = "std")]
 extern crate combine;
 
+use std::{cell::Cell, rc::Rc};
+
 use combine::{
     attempt, choice, many, many1,
     parser::{
-        char::{char, digit, spaces, string},
+        char::{char, digit, letter, spaces, string},
         combinator::recognize,
     },
     sep_by, skip_many1,
@@ -115,3 +117,72 @@ fn buffered_stream_recognize_issue_256() {
         })
     );
 }
+
+/// Iterator which records every time it gets cloned
+struct CountClones<I> {
+    iter: I,
+    clones: Rc<Cell<usize>>,
+}
+
+impl<I> Clone for CountClones<I>
+where
+    I: Clone,
+{
+    fn clone(&self) -> Self {
+        self.clones.set(self.clones.get() + 1);
+        CountClones {
+            iter: self.iter.clone(),
+            clones: self.clones.clone(),
+        }
+    }
+}
+
+impl<I> Iterator for CountClones<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+    fn next(&mut self) -> Option<I::Item> {
+        self.iter.next()
+    }
+}
+
+#[test]
+fn many_does_not_clone_the_underlying_input() {
+    const LEN: usize = 1 << 20;
+
+    let clones = Rc::new(Cell::new(0));
+    let iter = CountClones {
+        iter: std::iter::repeat('a').take(LEN),
+        clones: clones.clone(),
+    };
+    let stream = buffered::Stream::new(position::Stream::new(IteratorStream::new(iter)), 1);
+    let result = many::<String, _, _>(letter()).parse(stream).map(|t| t.0);
+    assert_eq!(result.map(|s| s.len()), Ok(LEN));
+    assert_eq!(clones.get(), 0);
+}
+
+#[test]
+fn expression_from_reader() {
+    use std::io::Cursor;
+
+    use combine::{
+        chainl1,
+        parser::byte::{byte, digit as byte_digit},
+        stream::read,
+    };
+
+    let number = many1(byte_digit()).map(|digits: Vec<u8>| {
+        digits
+            .iter()
+            .fold(0i64, |acc, &d| acc * 10 + i64::from(d - b'0'))
+    });
+    let product = chainl1(number, byte(b'*').map(|_| |l, r| l * r));
+    let mut expr = chainl1(product, byte(b'+').map(|_| |l, r| l + r));
+
+    let stream = buffered::Stream::new(
+        position::Stream::new(read::Stream::new(Cursor::new(&b"1+2*3+4"[..]))),
+        1,
+    );
+    assert_eq!(expr.parse(stream).map(|t| t.0), Ok(11));
+}
@@ -2,11 +2,14 @@ use combine::{
     parser::{
         byte::bytes_cmp,
         char::{digit, letter, string, string_cmp},
-        choice::{choice, optional},
-        combinator::{attempt, no_partial, not_followed_by},
+        choice::{choice, optional, permutation},
+        combinator::{attempt, look_ahead, no_partial, not_followed_by},
         error::unexpected,
         range::{self, range},
-        repeat::{count, count_min_max, many, sep_by, sep_end_by1, skip_until, take_until},
+        repeat::{
+            count, count_min_max, many, many1, sep_by, sep_end_by1, skip_count, skip_count_min_max,
+            skip_many, skip_until, take_until,
+        },
         token::{any, eof, position, token, value, Token},
     },
     EasyParser, Parser,
@@ -43,17 +46,88 @@ fn not_followed_by_does_not_consume_any_input() {
     assert!(parser.parse("aaa").is_err());
 }
 
+#[test]
+fn many_stops_when_parser_succeeds_without_consuming_input() {
+    let mut parser = many::<Vec<_>, _, _>(optional(digit()));
+    assert_eq!(
+        parser.parse("12abc"),
+        Ok((vec![Some('1'), Some('2')], "abc"))
+    );
+    assert_eq!(parser.parse("abc"), Ok((vec![], "abc")));
+
+    let mut parser = skip_many(many::<Vec<_>, _, _>(digit()));
+    assert_eq!(parser.parse("12abc"), Ok(((), "abc")));
+}
+
+#[test]
+fn sep_by_stops_when_separator_and_parser_succeed_without_consuming_input() {
+    let mut parser = sep_by::<Vec<_>, _, _, _>(optional(digit()), optional(token(',')));
+    assert_eq!(parser.parse("x"), Ok((vec![None], "x")));
+    assert_eq!(parser.parse("1,2x"), Ok((vec![Some('1'), Some('2')], "x")));
+
+    let mut parser = sep_end_by1::<Vec<_>, _, _, _>(optional(digit()), optional(token(',')));
+    assert_eq!(parser.parse("1,x"), Ok((vec![Some('1'), None], "x")));
+}
+
+#[test]
+fn attempt_backtracks_to_the_start_of_the_alternative() {
+    let mut parser = attempt(string("letter")).or(string("let"));
+    assert_eq!(parser.parse("letter"), Ok(("letter", "")));
+    assert_eq!(parser.parse("let x"), Ok(("let", " x")));
+
+    // Without `attempt` the first alternative commits to the shared "let" prefix
+    let mut parser = string("letter").or(string("let"));
+    assert!(parser.parse("let x").is_err());
+}
+
+#[test]
+fn look_ahead_does_not_consume_input() {
+    let mut parser = look_ahead(string("let"));
+    assert_eq!(parser.parse("let x"), Ok(("let", "let x")));
+    assert!(parser.parse("lex").is_err());
+
+    let mut parser = (look_ahead(digit()), many1::<String, _, _>(digit()));
+    assert_eq!(parser.parse("12a"), Ok((('1', "12".to_string()), "a")));
+}
+
+#[test]
+fn tuple_sequence_returns_flat_output() {
+    let mut date = (
+        digit(),
+        digit(),
+        digit(),
+        digit(),
+        token('-'),
+        digit(),
+        digit(),
+        token('-'),
+        digit(),
+        digit(),
+        token('T'),
+        digit(),
+    );
+    let ((y0, y1, y2, y3, _, m0, m1, _, d0, d1, _, h0), rest) = date.parse("2024-01-31T1").unwrap();
+    assert_eq!(
+        [y0, y1, y2, y3, m0, m1, d0, d1, h0],
+        ['2', '0', '2', '4', '0', '1', '3', '1', '1']
+    );
+    assert_eq!(rest, "");
+    assert!(date.parse("2024-01-31 1").is_err());
+}
+
 #[cfg(feature = "std")]
 mod tests_std {
 
     use combine::{
         parser::{
             byte::{alpha_num, bytes, num::be_u32},
-            char::{char, digit, letter},
+            char::{char, digit, hex_digit, letter, spaces},
+            token::{none_of, one_of},
         },
         stream::{
             easy::{self, Error, Errors},
             position::{self, SourcePosition},
+            IteratorStream,
         },
         Parser,
     };
@@ -256,6 +330,37 @@ mod tests_std {
         );
     }
 
+    #[test]
+    fn expected_labels_every_alternative() {
+        let mut expr = choice((digit(), letter(), char('('))).expected("expression");
+        assert_eq!(
+            expr.easy_parse(position::Stream::new(",")),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 1 },
+                errors: vec![
+                    Error::Unexpected(','.into()),
+                    Error::Expected("expression".into()),
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn merge_prefers_furthest_error() {
+        let furthest = || Errors::<char, &str, _> {
+            position: SourcePosition { line: 1, column: 3 },
+            errors: vec![Error::Unexpected('z'.into()), Error::Expected('c'.into())],
+        };
+        let first = || {
+            Errors::new(
+                SourcePosition { line: 1, column: 1 },
+                Error::Expected('x'.into()),
+            )
+        };
+        assert_eq!(first().merge(furthest()), furthest());
+        assert_eq!(furthest().merge(first()), furthest());
+    }
+
     #[test]
     fn try_tests() {
         // Ensure attempt adds error messages exactly once
@@ -397,6 +502,28 @@ mod tests_std {
         );
     }
 
+    #[test]
+    fn choice_vec_parser_empty_err() {
+        let mut parser = choice(vec![char('a'), char('b'), char('c')]);
+
+        assert_eq!(
+            parser.easy_parse(position::Stream::new("b")).map(|t| t.0),
+            Ok('b')
+        );
+        assert_eq!(
+            parser.easy_parse(position::Stream::new("d")),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 1 },
+                errors: vec![
+                    Error::Unexpected('d'.into()),
+                    Error::Expected('a'.into()),
+                    Error::Expected('b'.into()),
+                    Error::Expected('c'.into()),
+                ],
+            })
+        );
+    }
+
     #[test]
     fn sequence_in_choice_array_parser_empty_err_where_first_parser_delay_errors() {
         let mut p1 = char('1');
@@ -432,6 +559,32 @@ mod tests_std {
         );
     }
 
+    #[test]
+    fn count_min_max_exact_error_at_missing_element() {
+        let mut parser = string("\\u").with(count_min_max::<String, _, _>(4, 4, hex_digit()));
+        assert_eq!(
+            parser
+                .easy_parse(position::Stream::new("\\u00FF\""))
+                .map(|t| (t.0, t.1.input)),
+            Ok(("00FF".to_string(), "\""))
+        );
+        assert_eq!(
+            parser.easy_parse(position::Stream::new("\\u00F\"")),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 6 },
+                errors: vec![
+                    Error::Message("expected 1 more elements".into()),
+                    Error::Unexpected('"'.into()),
+                    Error::Expected("hexadecimal digit".into()),
+                ],
+            })
+        );
+        assert_eq!(
+            count_min_max::<Vec<_>, _, _>(0, 0, hex_digit()).parse("F"),
+            Ok((vec![], "F"))
+        );
+    }
+
     #[test]
     fn sequence_parser_resets_partial_state_issue_168() {
         assert_eq!(
@@ -664,7 +817,1438 @@ mod tests_std {
         let mut parser = string("let").skip(not_followed_by(eof().map(|_| "EOF")));
         assert_eq!(
             parser.easy_parse("let").map_err(|err| err.errors),
-            Err(vec![]),
+            Err(vec![Error::Unexpected("EOF".into())]),
+        );
+    }
+
+    #[test]
+    fn skip_eof_rejects_trailing_input() {
+        let mut parser = many1::<String, _, _>(digit()).skip(eof());
+        assert_eq!(parser.parse("123"), Ok(("123".to_string(), "")));
+
+        let err = many1::<String, _, _>(digit())
+            .skip(eof())
+            .easy_parse(position::Stream::new("12 "))
+            .unwrap_err();
+        assert_eq!(err.position, SourcePosition { line: 1, column: 3 });
+        assert!(err.to_string().contains("`end of input`"), "{}", err);
+    }
+
+    #[test]
+    fn parse_all_requires_end_of_input() {
+        assert_eq!(
+            many::<String, _, _>(digit()).parse_all("123"),
+            Ok("123".to_string())
+        );
+        assert_eq!(
+            many::<String, _, _>(digit()).parse_all(easy::Stream(position::Stream::new("123abc"))),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 4 },
+                errors: vec![
+                    Error::Unexpected('a'.into()),
+                    Error::Expected("digit".into()),
+                    Error::Expected("end of input".into()),
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn choice_over_boxed_parsers_reports_every_alternative() {
+        type Input<'a> = easy::Stream<position::Stream<&'a str, SourcePosition>>;
+
+        let mut keywords: Vec<Box<dyn Parser<Input, Output = &str, PartialState = ()>>> = vec![
+            no_partial(string("let")).boxed(),
+            no_partial(string("match")).boxed(),
+            no_partial(string("if")).boxed(),
+        ];
+        let mut parser = spaces().with(choice(&mut keywords[..]));
+
+        assert_eq!(
+            parser
+                .parse(easy::Stream(position::Stream::new("  match")))
+                .map(|t| t.0),
+            Ok("match")
+        );
+        assert_eq!(
+            parser.parse(easy::Stream(position::Stream::new("  x"))),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 3 },
+                errors: vec![
+                    Error::Unexpected('x'.into()),
+                    Error::Expected("whitespace".into()),
+                    Error::Expected("let".into()),
+                    Error::Expected("match".into()),
+                    Error::Expected("if".into()),
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn exact_count_of_hex_digits() {
+        let mut escape = char('\\').with(char('u')).with(
+            count_min_max::<String, _, _>(4, 4, hex_digit())
+                .map(|digits| u32::from_str_radix(&digits, 16).unwrap()),
+        );
+        let mut parse = |s| {
+            escape
+                .easy_parse(position::Stream::new(s))
+                .map(|(value, rest)| (value, rest.input))
+        };
+        assert_eq!(parse("\\u00e9x"), Ok((0xe9, "x")));
+        assert_eq!(parse("\\u00e9f"), Ok((0xe9, "f")));
+
+        let err = parse("\\u0e\"").unwrap_err();
+        assert_eq!(err.position, SourcePosition { line: 1, column: 5 });
+        assert!(err
+            .errors
+            .contains(&Error::Message("expected 2 more elements".into())));
+    }
+
+    #[test]
+    fn skip_exact_count_of_padding() {
+        let mut record = skip_count_min_max(3, 3, char('.')).with(many1::<String, _, _>(letter()));
+        let mut parse = |s| {
+            record
+                .easy_parse(position::Stream::new(s))
+                .map(|(value, rest)| (value, rest.input))
+        };
+        assert_eq!(parse("...abc"), Ok(("abc".to_string(), "")));
+
+        let err = parse("..abc").unwrap_err();
+        assert_eq!(err.position, SourcePosition { line: 1, column: 3 });
+        assert!(err.errors.contains(&Error::Expected('.'.into())));
+
+        let err = parse("....abc").unwrap_err();
+        assert_eq!(err.position, SourcePosition { line: 1, column: 4 });
+
+        assert_eq!(
+            skip_count(3, char('.')).parse(".."),
+            Ok(((), "")),
+            "skip_count alone accepts fewer repetitions"
+        );
+    }
+
+    #[test]
+    fn bounded_count_of_hex_digits() {
+        let mut parser = count_min_max::<String, _, _>(1, 6, hex_digit());
+        assert_eq!(parser.parse("a"), Ok(("a".to_string(), "")));
+        assert_eq!(parser.parse("1234567"), Ok(("123456".to_string(), "7")));
+        assert_eq!(parser.parse("ffz"), Ok(("ff".to_string(), "z")));
+        assert!(parser.parse("z").is_err());
+    }
+
+    #[test]
+    fn many_till_fails_when_parser_succeeds_without_consuming_input() {
+        use combine::parser::repeat::many_till;
+
+        let mut parser = many_till::<Vec<_>, _, _, _>(optional(char('a')), char('b'));
+        assert_eq!(
+            parser.easy_parse(position::Stream::new("aab")).map(|t| t.0),
+            Ok((vec![Some('a'), Some('a')], 'b'))
+        );
+        assert_eq!(
+            parser.easy_parse(position::Stream::new("c")),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 1 },
+                errors: vec![
+                    Error::Unexpected('c'.into()),
+                    Error::Expected('b'.into()),
+                    Error::Expected('a'.into()),
+                ],
+            })
+        );
+        assert_eq!(
+            parser.easy_parse(position::Stream::new("ac")),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 2 },
+                errors: vec![
+                    Error::Unexpected('c'.into()),
+                    Error::Expected('b'.into()),
+                    Error::Expected('a'.into()),
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn many_till_reports_end_and_element_errors() {
+        use combine::parser::repeat::many_till;
+
+        let mut parser = many_till::<String, _, _, _>(letter(), char(';'));
+        assert_eq!(
+            parser.easy_parse(position::Stream::new("ab;")).map(|t| t.0),
+            Ok(("ab".to_string(), ';'))
+        );
+        assert_eq!(
+            parser.easy_parse(position::Stream::new("ab1")),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 3 },
+                errors: vec![
+                    Error::Unexpected('1'.into()),
+                    Error::Expected(';'.into()),
+                    Error::Expected("letter".into()),
+                ],
+            })
+        );
+        assert_eq!(
+            parser.easy_parse(position::Stream::new("1")),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 1 },
+                errors: vec![
+                    Error::Unexpected('1'.into()),
+                    Error::Expected(';'.into()),
+                    Error::Expected("letter".into()),
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn many_till_does_not_keep_elements_from_a_failed_parse() {
+        use combine::parser::repeat::many_till;
+
+        let mut parser = many_till::<String, _, _, _>(letter(), char(';'));
+        let mut state = Default::default();
+        assert!(parser.parse_with_state(&mut "ab!", &mut state).is_err());
+        assert_eq!(
+            parser.parse_with_state(&mut "cd;", &mut state),
+            Ok(("cd".to_string(), ';'))
+        );
+    }
+
+    #[test]
+    fn skip_many_discards_output() {
+        use combine::parser::{char::space, repeat::skip_many1};
+
+        let mut parser = skip_many(space()).with(letter());
+        assert_eq!(parser.parse("  \tx"), Ok(('x', "")));
+        assert_eq!(parser.parse("x"), Ok(('x', "")));
+
+        assert_eq!(skip_many1(space()).parse(" \n x"), Ok(((), "x")));
+        assert_eq!(
+            skip_many1(space()).easy_parse(position::Stream::new("x")),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 1 },
+                errors: vec![
+                    Error::Unexpected('x'.into()),
+                    Error::Expected("whitespace".into()),
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn sep_by1_requires_an_element() {
+        use combine::{between, sep_by1};
+
+        let mut args = between(
+            char('('),
+            char(')'),
+            sep_by1::<Vec<_>, _, _, _>(digit(), char(',')),
+        );
+        let mut parse = |s| {
+            args.easy_parse(position::Stream::new(s))
+                .map(|(output, rest)| (output, rest.input))
+        };
+        assert_eq!(parse("(1,2)"), Ok((vec!['1', '2'], "")));
+        assert_eq!(
+            parse("()"),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 2 },
+                errors: vec![
+                    Error::Unexpected(')'.into()),
+                    Error::Expected("digit".into())
+                ],
+            })
+        );
+        assert_eq!(
+            parse("(1,)"),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 4 },
+                errors: vec![
+                    Error::Unexpected(')'.into()),
+                    Error::Expected("digit".into())
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn sep_end_by_accepts_trailing_separator() {
+        use combine::{
+            between,
+            parser::char::{integer, lex},
+            sep_end_by, sep_end_by1,
+        };
+
+        let list = || {
+            between(
+                lex(char('[')),
+                char(']'),
+                sep_end_by::<Vec<_>, _, _, _>(lex(integer()), lex(char(','))),
+            )
+        };
+        assert_eq!(list().parse("[1, 2, 3,]"), Ok((vec![1, 2, 3], "")));
+        assert_eq!(list().parse("[1, 2, 3]"), Ok((vec![1, 2, 3], "")));
+        assert_eq!(list().parse("[]"), Ok((vec![], "")));
+        assert!(list().parse("[1,,]").is_err());
+
+        let mut parser = sep_end_by1::<Vec<_>, _, _, _>(digit(), char(','));
+        assert_eq!(parser.parse("1,2,x"), Ok((vec!['1', '2'], "x")));
+        assert!(parser.parse(",").is_err());
+    }
+
+    #[test]
+    fn chainl1_and_chainr1_associativity() {
+        use combine::{chainl1, chainr1, parser::char::integer};
+
+        let sub = char('-').map(|_| |l: i64, r: i64| l - r);
+        assert_eq!(chainl1(integer(), sub).parse("8-3-2"), Ok((3, "")));
+
+        let pow = char('^').map(|_| |l: i64, r: i64| l.pow(r as u32));
+        assert_eq!(chainr1(integer(), pow).parse("2^3^2"), Ok((512, "")));
+
+        let sub = char('-').map(|_| |l: i64, r: i64| l - r);
+        assert_eq!(chainl1(integer(), sub).parse("7"), Ok((7, "")));
+    }
+
+    #[test]
+    fn then_chooses_parser_from_earlier_output() {
+        #[derive(Debug, PartialEq)]
+        enum Value {
+            Int(u32),
+            Str(String),
+        }
+
+        let mut parser = any().then(|tag| match tag {
+            'i' => many1(digit())
+                .map(|s: String| Value::Int(s.parse().unwrap()))
+                .left(),
+            _ => many1(letter()).map(Value::Str).right(),
+        });
+        assert_eq!(parser.parse("i42"), Ok((Value::Int(42), "")));
+        assert_eq!(parser.parse("sab1"), Ok((Value::Str("ab".into()), "1")));
+        assert!(parser.parse("iab").is_err());
+
+        // Parse a count and then exactly that many elements
+        let mut parser = digit().then(|n| {
+            let n = n.to_digit(10).unwrap() as usize;
+            count_min_max::<String, _, _>(n, n, letter())
+        });
+        assert_eq!(parser.parse("3abcd"), Ok(("abc".to_string(), "d")));
+        assert!(parser.parse("3ab").is_err());
+    }
+
+    #[test]
+    fn and_then_reports_conversion_errors() {
+        let mut number = many1::<Vec<char>, _, _>(digit()).and_then(|digits| {
+            digits
+                .iter()
+                .try_fold(0i64, |acc, d| {
+                    acc.checked_mul(10)?
+                        .checked_add(i64::from(d.to_digit(10).unwrap()))
+                })
+                .ok_or_else(|| Error::Message("integer overflow".into()))
+        });
+        let mut parse = |s| {
+            (char('='), number.by_ref())
+                .map(|t| t.1)
+                .easy_parse(position::Stream::new(s))
+                .map(|t| t.0)
+        };
+
+        assert_eq!(parse("=9223372036854775807"), Ok(i64::max_value()));
+        assert_eq!(
+            parse("=9223372036854775808"),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 2 },
+                errors: vec![Error::Message("integer overflow".into())],
+            })
+        );
+    }
+
+    #[test]
+    fn value_and_fail_consume_nothing() {
+        use combine::parser::error::fail;
+
+        let mut parser = char('-').map(|_| -1).or(value(1));
+        assert_eq!(parser.parse("-5"), Ok((-1, "5")));
+        assert_eq!(parser.parse("5"), Ok((1, "5")));
+
+        let mut parser = (char('#'), fail::<_, _, ()>("reserved"));
+        assert_eq!(
+            parser.easy_parse(position::Stream::new("#x")),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 2 },
+                errors: vec![
+                    Error::Unexpected('x'.into()),
+                    Error::Message("reserved".into())
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn unexpected_at_reserved_word() {
+        use combine::{look_ahead, parser::error::unexpected_any};
+
+        let ident = || many1::<String, _, _>(letter());
+        let mut parser = spaces().with(look_ahead(ident()).then(|word| {
+            if word == "let" {
+                unexpected_any("reserved word").left()
+            } else {
+                ident().right()
+            }
+        }));
+        let mut parse = |s| parser.easy_parse(position::Stream::new(s)).map(|t| t.0);
+        assert_eq!(parse("  lets"), Ok("lets".to_string()));
+        let err = parse("  let").unwrap_err();
+        assert_eq!(err.position, SourcePosition { line: 1, column: 3 });
+        assert!(err
+            .errors
+            .contains(&Error::Unexpected("reserved word".into())));
+    }
+
+    #[test]
+    fn expected_replaces_inner_expected_errors() {
+        let mut parser = (char('-'), many1::<String, _, _>(digit()))
+            .or(many1(digit()).map(|s| ('+', s)))
+            .expected("integer literal");
+        let mut parse = |s| parser.easy_parse(position::Stream::new(s)).map(|t| t.0);
+        assert_eq!(parse("-12"), Ok(('-', "12".to_string())));
+        assert_eq!(
+            parse("x"),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 1 },
+                errors: vec![
+                    Error::Unexpected('x'.into()),
+                    Error::Expected("integer literal".into()),
+                ],
+            })
+        );
+
+        // Once input has been committed the errors from the inner parser are kept
+        assert_eq!(
+            parse("-x"),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 2 },
+                errors: vec![
+                    Error::Unexpected('x'.into()),
+                    Error::Expected("digit".into())
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn map_err_rewrites_committed_errors() {
+        let mut parser = spaces().with((char('('), digit(), char(')')).map_err(
+            |mut err: Errors<_, _, _>| {
+                err.add_error(Error::Message("in parenthesized digit".into()));
+                err
+            },
+        ));
+        assert_eq!(
+            parser.easy_parse(position::Stream::new(" (1x")),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 4 },
+                errors: vec![
+                    Error::Unexpected('x'.into()),
+                    Error::Expected(')'.into()),
+                    Error::Message("in parenthesized digit".into()),
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn map_err_leaves_uncommitted_errors_to_the_surrounding_parsers() {
+        let mut parser = digit()
+            .map_err(|mut err: Errors<_, _, _>| {
+                err.errors.clear();
+                err
+            })
+            .expected("number");
+        assert_eq!(
+            parser.easy_parse(position::Stream::new("x")),
+            Err(Errors {
+                position: SourcePosition::default(),
+                errors: vec![
+                    Error::Unexpected('x'.into()),
+                    Error::Expected("number".into()),
+                ],
+            })
+        );
+
+        let mut parser = (
+            optional(char('-').map_err(|err: Errors<_, _, _>| err).silent()),
+            digit(),
+        );
+        assert_eq!(
+            parser.easy_parse(position::Stream::new("x")),
+            Err(Errors {
+                position: SourcePosition::default(),
+                errors: vec![
+                    Error::Unexpected('x'.into()),
+                    Error::Expected("digit".into()),
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn spanned_nodes_across_lines() {
+        use combine::parser::{char::lex, combinator::spanned};
+        use std::ops::Range;
+
+        #[derive(Debug, PartialEq)]
+        struct Ident {
+            name: String,
+            span: Range<SourcePosition>,
+        }
+
+        let ident =
+            spanned(many1::<String, _, _>(letter())).map(|(span, name)| Ident { name, span });
+        let mut parser = spaces().with(many::<Vec<_>, _, _>(lex(ident)));
+
+        let pos = |line, column| SourcePosition { line, column };
+        assert_eq!(
+            parser
+                .easy_parse(position::Stream::new("ab\n  cde "))
+                .map(|t| t.0),
+            Ok(vec![
+                Ident {
+                    name: "ab".into(),
+                    span: pos(1, 1)..pos(1, 3),
+                },
+                Ident {
+                    name: "cde".into(),
+                    span: pos(2, 3)..pos(2, 6),
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn position_consumes_nothing() {
+        let mut parser = (
+            spaces(),
+            position(),
+            many1::<String, _, _>(letter()),
+            position(),
+        )
+            .map(|(_, start, word, end)| (start, word, end));
+        assert_eq!(
+            parser
+                .easy_parse(position::Stream::new("\n\n  word!"))
+                .map(|(output, rest)| (output, rest.input)),
+            Ok((
+                (
+                    SourcePosition { line: 3, column: 3 },
+                    "word".to_string(),
+                    SourcePosition { line: 3, column: 7 }
+                ),
+                "!"
+            ))
+        );
+
+        assert_eq!(
+            position()
+                .parse(position::Stream::new("abc"))
+                .map(|(pos, rest)| (pos, rest.input)),
+            Ok((SourcePosition { line: 1, column: 1 }, "abc"))
+        );
+    }
+
+    #[test]
+    fn take_until_delimiters() {
+        use combine::parser::range::take_until_range;
+
+        let field = || take_until_range("\t").skip(char('\t'));
+        assert_eq!(
+            (field(), field(), range::take_while(|_| true)).parse("GET\t/index.html\t200"),
+            Ok((("GET", "/index.html", "200"), ""))
+        );
+
+        let mut comment = (
+            string("/*"),
+            take_until::<String, _, _>(attempt(string("*/"))),
+            string("*/"),
+        )
+            .map(|t| t.1);
+        assert_eq!(
+            comment.parse("/* a * b */x"),
+            Ok((" a * b ".to_string(), "x"))
+        );
+        assert!(comment.parse("/* unterminated").is_err());
+    }
+
+    #[test]
+    fn fold_many_accumulates_without_collecting() {
+        use combine::parser::repeat::{fold_many, fold_many1};
+
+        let mut sum = fold_many(digit().skip(char(',')), 0, |acc, d| {
+            acc + d.to_digit(10).unwrap()
+        });
+        assert_eq!(sum.parse("1,2,3,x"), Ok((6, "x")));
+        assert_eq!(sum.parse("x"), Ok((0, "x")));
+        assert!(sum.parse("1,2x").is_err());
+
+        let mut word = fold_many1(letter(), String::new(), |mut acc, c| {
+            acc.push(c.to_ascii_uppercase());
+            acc
+        });
+        assert_eq!(
+            word.easy_parse(position::Stream::new("ab1")).map(|t| t.0),
+            Ok("AB".to_string())
+        );
+        assert_eq!(
+            word.easy_parse(position::Stream::new("1")),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 1 },
+                errors: vec![
+                    Error::Unexpected('1'.into()),
+                    Error::Expected("letter".into())
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn many_into_sets_and_custom_collections() {
+        use std::collections::BTreeSet;
+
+        assert_eq!(
+            many::<BTreeSet<_>, _, _>(letter()).parse("cabba1"),
+            Ok((['a', 'b', 'c'].iter().cloned().collect(), "1"))
+        );
+
+        // Any `Extend + Default` type can be used, here one which only counts the elements
+        #[derive(Default, Debug, PartialEq)]
+        struct Counter(usize);
+        impl<T> Extend<T> for Counter {
+            fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+                self.0 += iter.into_iter().count();
+            }
+        }
+        assert_eq!(
+            many1::<Counter, _, _>(digit()).parse("1234x"),
+            Ok((Counter(4), "x"))
+        );
+    }
+
+    #[test]
+    fn sep_by_into_btree_map() {
+        use combine::parser::char::{alpha_num, lex};
+        use std::collections::BTreeMap;
+
+        let word = || lex(many1::<String, _, _>(alpha_num()));
+        let entry = (word(), lex(char('=')), word()).map(|(k, _, v)| (k, v));
+        let mut config = sep_by::<BTreeMap<_, _>, _, _, _>(entry, lex(char(';')));
+
+        let (map, rest) = config.parse("name = x ; port=80;debug= 1").unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(
+            map.into_iter().collect::<Vec<_>>(),
+            vec![
+                ("debug".to_string(), "1".to_string()),
+                ("name".to_string(), "x".to_string()),
+                ("port".to_string(), "80".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn permutation_of_attributes() {
+        let attribute = |name| char(name).skip(char('=')).with(digit()).skip(spaces());
+        let mut attributes =
+            permutation((attribute('a'), attribute('b'), optional(attribute('c')))).skip(eof());
+        let mut parse = |s| {
+            attributes
+                .easy_parse(position::Stream::new(s))
+                .map(|(value, rest)| (value, rest.input))
+        };
+        assert_eq!(parse("a=1 b=2"), Ok((('1', '2', None), "")));
+        assert_eq!(parse("b=2 c=3 a=1"), Ok((('1', '2', Some('3')), "")));
+        assert_eq!(parse("c=3 b=2 a=1"), Ok((('1', '2', Some('3')), "")));
+
+        let err = parse("b=2").unwrap_err();
+        assert_eq!(err.position, SourcePosition { line: 1, column: 4 });
+        assert!(err.errors.contains(&Error::Expected('a'.into())));
+
+        let err = parse("a=1 a=2 b=3").unwrap_err();
+        assert_eq!(err.position, SourcePosition { line: 1, column: 5 });
+        assert!(err.errors.contains(&Error::Unexpected('a'.into())));
+        assert!(err.errors.contains(&Error::Expected('b'.into())));
+        assert!(!err.errors.contains(&Error::Expected('a'.into())));
+
+        let err = parse("d=4").unwrap_err();
+        assert_eq!(err.position, SourcePosition { line: 1, column: 1 });
+        assert!(err.errors.contains(&Error::Expected('a'.into())));
+        assert!(err.errors.contains(&Error::Expected('b'.into())));
+    }
+
+    #[test]
+    fn dispatch_on_peeked_keyword() {
+        use combine::{dispatch, parser::error::fail};
+
+        #[derive(Debug, PartialEq)]
+        enum Statement {
+            Let(String),
+            Return(u32),
+            Loop,
+        }
+
+        let mut statement = look_ahead(letter()).then(|c| {
+            dispatch!(c;
+                'l' => choice((
+                    attempt(string("let ")).with(many1(letter())).map(Statement::Let),
+                    string("loop").map(|_| Statement::Loop),
+                )),
+                'r' => string("return ")
+                    .with(many1(digit()))
+                    .map(|digits: String| Statement::Return(digits.parse().unwrap())),
+                _ => fail("statement"),
+            )
+        });
+        let mut parse = |s| {
+            statement
+                .easy_parse(position::Stream::new(s))
+                .map(|(value, rest)| (value, rest.input))
+        };
+        assert_eq!(parse("let x"), Ok((Statement::Let("x".into()), "")));
+        assert_eq!(parse("loop"), Ok((Statement::Loop, "")));
+        assert_eq!(parse("return 12"), Ok((Statement::Return(12), "")));
+
+        // The `r` branch has been chosen so the error comes from `return` alone
+        let err = parse("retry").unwrap_err();
+        assert_eq!(err.position, SourcePosition { line: 1, column: 4 });
+        assert!(err.errors.contains(&Error::Expected("return ".into())));
+        assert!(!err.errors.contains(&Error::Expected("loop".into())));
+
+        let err = parse("if").unwrap_err();
+        assert_eq!(err.position, SourcePosition { line: 1, column: 1 });
+        assert!(err.errors.contains(&Error::Message("statement".into())));
+    }
+
+    #[test]
+    fn forward_ref_mutually_recursive_values() {
+        use combine::parser::{combinator::forward_ref, sequence::between};
+
+        #[derive(Debug, PartialEq)]
+        enum Value {
+            Number(u32),
+            List(Vec<Value>),
+        }
+
+        let (open, close) = ('[', ']');
+        let value = forward_ref::<easy::Stream<position::Stream<&str, SourcePosition>>, Value>();
+        let list = forward_ref();
+        {
+            let value = value.clone();
+            list.define(move || {
+                between(
+                    char(open).skip(spaces()),
+                    char(close).skip(spaces()),
+                    sep_by(value.clone(), char(',').skip(spaces())),
+                )
+                .map(Value::List)
+            });
+        }
+        {
+            let list = list.clone();
+            value.define(move || {
+                many1(digit())
+                    .map(|digits: String| Value::Number(digits.parse().unwrap()))
+                    .skip(spaces())
+                    .or(list.clone())
+            });
+        }
+
+        let mut parser = value.clone();
+        assert_eq!(
+            parser
+                .easy_parse(position::Stream::new("[1, [2, []], 3]"))
+                .map(|(value, rest)| (value, rest.input)),
+            Ok((
+                Value::List(vec![
+                    Value::Number(1),
+                    Value::List(vec![Value::Number(2), Value::List(vec![])]),
+                    Value::Number(3),
+                ]),
+                ""
+            ))
+        );
+
+        let err = parser
+            .easy_parse(position::Stream::new("[1, [2 3]"))
+            .unwrap_err();
+        assert_eq!(err.position, SourcePosition { line: 1, column: 8 });
+        assert!(err.errors.contains(&Error::Expected(']'.into())));
+    }
+
+    #[test]
+    fn env_parser_consults_a_symbol_table() {
+        use combine::{
+            parser::function::env_parser,
+            stream::{Positioned, ResetStream},
+            StdParseResult,
+        };
+        use std::collections::HashMap;
+
+        type Input<'a> = easy::Stream<position::Stream<&'a str, SourcePosition>>;
+
+        struct Symbols {
+            ids: HashMap<&'static str, u32>,
+        }
+
+        impl Symbols {
+            fn symbol<'a>(&self, input: &mut Input<'a>) -> StdParseResult<u32, Input<'a>> {
+                let before = input.checkpoint();
+                let (name, committed) = many1::<String, _, _>(letter())
+                    .parse_stream(input)
+                    .into_result()?;
+                match self.ids.get(&name[..]) {
+                    Some(&id) => Ok((id, committed)),
+                    None => {
+                        input.reset(before).unwrap();
+                        let message = format!("unknown symbol `{}`", name);
+                        Err(committed.map(|()| {
+                            Errors::new(input.position(), Error::Message(message.into())).into()
+                        }))
+                    }
+                }
+            }
+        }
+
+        let symbols = Symbols {
+            ids: vec![("x", 1), ("y", 2)].into_iter().collect(),
+        };
+        let mut parser = sep_by(env_parser(&symbols, Symbols::symbol), char(','));
+        let mut parse = |s| {
+            parser
+                .easy_parse(position::Stream::new(s))
+                .map(|(value, rest)| (value, rest.input))
+        };
+
+        assert_eq!(parse("x,y,x"), Ok((vec![1, 2, 1], "")));
+        let err = parse("x,z").unwrap_err();
+        assert_eq!(err.position, SourcePosition { line: 1, column: 3 });
+        assert_eq!(
+            err.errors,
+            vec![Error::Message("unknown symbol `z`".into())]
+        );
+    }
+
+    #[test]
+    fn choice_macro_merges_the_errors_of_each_alternative() {
+        use combine::choice;
+
+        let mut literal = choice!(
+            string("true").map(|_| "bool"),
+            many1::<String, _, _>(digit()).map(|_| "number"),
+            char('"')
+                .with(skip_many(none_of("\"".chars())))
+                .skip(char('"'))
+                .map(|_| "string"),
+        );
+        let mut parse = |s| {
+            literal
+                .easy_parse(position::Stream::new(s))
+                .map(|(value, rest)| (value, rest.input))
+        };
+        assert_eq!(parse("true"), Ok(("bool", "")));
+        assert_eq!(parse("42"), Ok(("number", "")));
+        assert_eq!(parse("\"a\""), Ok(("string", "")));
+
+        let err = parse("x").unwrap_err();
+        assert_eq!(err.position, SourcePosition { line: 1, column: 1 });
+        assert_eq!(
+            err.errors,
+            vec![
+                Error::Unexpected('x'.into()),
+                Error::Expected("true".into()),
+                Error::Expected("digit".into()),
+                Error::Expected('"'.into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn cut_reports_only_the_chosen_branch() {
+        use combine::parser::combinator::cut;
+
+        // The sign is optional so `number` fails without consuming anything on a letter
+        let number = || optional(char('-')).with(many1::<String, _, _>(digit()));
+        let mut parser = choice((cut(number()), many1(letter())));
+        assert_eq!(
+            parser
+                .easy_parse(position::Stream::new("-12"))
+                .map(|(value, rest)| (value, rest.input)),
+            Ok(("12".to_string(), ""))
+        );
+        let err = parser.easy_parse(position::Stream::new("abc")).unwrap_err();
+        assert_eq!(err.position, SourcePosition { line: 1, column: 1 });
+        assert_eq!(
+            err.errors,
+            vec![
+                Error::Unexpected('a'.into()),
+                Error::Expected('-'.into()),
+                Error::Expected("digit".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn cut_keeps_expected_errors_of_the_wrapped_parser() {
+        use combine::parser::combinator::cut;
+
+        let mut parser = (char('a'), cut(optional(char('b'))), char('c'));
+        let err = parser.easy_parse(position::Stream::new("ax")).unwrap_err();
+        assert_eq!(err.position, SourcePosition { line: 1, column: 2 });
+        assert_eq!(
+            err.errors,
+            vec![
+                Error::Unexpected('x'.into()),
+                Error::Expected('b'.into()),
+                Error::Expected('c'.into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn silent_probe_does_not_pollute_expected_errors() {
+        let number = || many1::<String, _, _>(digit());
+        let parse = |s, silence: bool| {
+            let sign = if silence {
+                optional(one_of("+-".chars())).silent().left()
+            } else {
+                optional(one_of("+-".chars())).right()
+            };
+            (sign, number())
+                .easy_parse(position::Stream::new(s))
+                .map(|(value, rest)| (value, rest.input))
+        };
+        assert_eq!(parse("-1", true), Ok(((Some('-'), "1".to_string()), "")));
+
+        let err = parse("x", false).unwrap_err();
+        assert!(err.errors.contains(&Error::Expected('+'.into())));
+
+        let err = parse("x", true).unwrap_err();
+        assert_eq!(
+            err.errors,
+            vec![
+                Error::Unexpected('x'.into()),
+                Error::Expected("digit".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn or_value_defaults_only_without_committing() {
+        let mut weight = many1::<String, _, _>(letter())
+            .and(char('=').with(many1(digit())).or_value("1".to_string()));
+        let mut parse = |s| {
+            weight
+                .easy_parse(position::Stream::new(s))
+                .map(|(value, rest)| (value, rest.input))
+        };
+        assert_eq!(parse("a=3"), Ok((("a".into(), "3".into()), "")));
+        assert_eq!(parse("a,"), Ok((("a".into(), "1".into()), ",")));
+
+        let err = parse("a=,").unwrap_err();
+        assert_eq!(err.position, SourcePosition { line: 1, column: 3 });
+        assert!(err.errors.contains(&Error::Expected("digit".into())));
+
+        let err = parse("1").unwrap_err();
+        assert_eq!(err.position, SourcePosition { line: 1, column: 1 });
+        assert!(err.errors.contains(&Error::Expected("letter".into())));
+    }
+
+    #[test]
+    fn flat_map_validates_escape_sequences() {
+        let mut escape = char('\\').with(any()).flat_map(|c| match c {
+            'n' => Ok('\n'),
+            't' => Ok('\t'),
+            '\\' | '"' => Ok(c),
+            _ => Err(Errors::new(
+                SourcePosition::default(),
+                Error::Message(format!("invalid escape `\\{}`", c).into()),
+            )),
+        });
+        assert_eq!(
+            escape
+                .easy_parse(position::Stream::new("\\n"))
+                .map(|(value, rest)| (value, rest.input)),
+            Ok(('\n', ""))
+        );
+
+        let err = escape.easy_parse(position::Stream::new("\\q")).unwrap_err();
+        assert_eq!(
+            err.errors,
+            vec![Error::Message("invalid escape `\\q`".into())]
+        );
+    }
+
+    #[test]
+    fn map_with_position_records_node_starts() {
+        #[derive(Debug, PartialEq)]
+        struct Node {
+            value: u32,
+            start: SourcePosition,
+        }
+
+        let node = many1(digit()).map_with_position(|digits: String, start| Node {
+            value: digits.parse().unwrap(),
+            start,
+        });
+        let mut nodes = sep_by(node, (char(','), spaces()));
+        let result = nodes
+            .easy_parse(position::Stream::new("1,\n  22, 3"))
+            .map(|(value, rest)| (value, rest.input));
+        assert_eq!(
+            result,
+            Ok((
+                vec![
+                    Node {
+                        value: 1,
+                        start: SourcePosition { line: 1, column: 1 },
+                    },
+                    Node {
+                        value: 22,
+                        start: SourcePosition { line: 2, column: 3 },
+                    },
+                    Node {
+                        value: 3,
+                        start: SourcePosition { line: 2, column: 7 },
+                    },
+                ],
+                ""
+            ))
+        );
+    }
+
+    #[test]
+    fn hexdump_of_a_plain_byte_slice() {
+        let input = &b"header: value\n0123456789"[..];
+        let err = (bytes(b"header: value\n"), be_u32(), bytes(b";"))
+            .easy_parse(input)
+            .unwrap_err()
+            .map_position(|p| p.translate_position(input));
+        assert_eq!(
+            err.hexdump(input).to_string(),
+            "\
+Parse error at offset 0x12
+00000000  68 65 61 64 65 72 3a 20  76 61 6c 75 65 0a 30 31  |header: value.01|
+00000010  32 33 34 35 36 37 38 39                           |23456789|
+                ^^
+Unexpected `0x34`
+Expected `0x3b`
+"
+        );
+    }
+
+    #[test]
+    fn choice_macro_accepts_more_than_25_alternatives() {
+        use combine::choice;
+
+        let mut parser = choice!(
+            char('a'),
+            char('b'),
+            char('c'),
+            char('d'),
+            char('e'),
+            char('f'),
+            char('g'),
+            char('h'),
+            char('i'),
+            char('j'),
+            char('k'),
+            char('l'),
+            char('m'),
+            char('n'),
+            char('o'),
+            char('p'),
+            char('q'),
+            char('r'),
+            char('s'),
+            char('t'),
+            char('u'),
+            char('v'),
+            char('w'),
+            char('x'),
+            char('y'),
+            char('z'),
+            char('0'),
+            char('1'),
+        );
+        let mut parse = |s| parser.easy_parse(position::Stream::new(s)).map(|t| t.0);
+        assert_eq!(parse("a"), Ok('a'));
+        assert_eq!(parse("y"), Ok('y'));
+        assert_eq!(parse("1"), Ok('1'));
+
+        let err = parse("2").unwrap_err();
+        assert_eq!(err.errors.len(), 29);
+        assert_eq!(err.errors[0], Error::Unexpected('2'.into()));
+        assert_eq!(err.errors[1], Error::Expected('a'.into()));
+        assert_eq!(err.errors[28], Error::Expected('1'.into()));
+    }
+
+    #[test]
+    fn one_of_lists_the_allowed_tokens() {
+        assert_eq!(one_of("+-*".chars()).parse("*2"), Ok(('*', "2")));
+        assert_eq!(
+            one_of("+-*".chars()).easy_parse(position::Stream::new("/")),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 1 },
+                errors: vec![
+                    Error::Unexpected('/'.into()),
+                    Error::Expected('+'.into()),
+                    Error::Expected('-'.into()),
+                    Error::Expected('*'.into()),
+                ],
+            })
+        );
+
+        let result = many::<String, _, _>(one_of("ab".chars()))
+            .parse(position::Stream::new(IteratorStream::new("abac".chars())))
+            .map(|t| t.0);
+        assert_eq!(result, Ok("aba".to_string()));
+        let err = one_of("+-*/".chars())
+            .easy_parse(position::Stream::new("%"))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parse error at line: 1, column: 1\n\
+             Unexpected `%`\n\
+             Expected `+`, `-`, `*` or `/`\n"
+        );
+    }
+
+    #[test]
+    fn none_of_stops_at_excluded_token() {
+        let mut parser = char('(').with(many::<String, _, _>(none_of(")".chars())));
+        assert_eq!(parser.parse("(a b))"), Ok(("a b".to_string(), "))")));
+        assert_eq!(parser.parse("()"), Ok(("".to_string(), ")")));
+    }
+
+    #[test]
+    fn custom_parser_errors_from_outside_the_crate() {
+        use combine::{parser, stream::Positioned};
+
+        type Input = easy::Stream<position::Stream<&'static str, SourcePosition>>;
+
+        let mut even_digit = parser(|input: &mut Input| {
+            let position = input.position();
+            let (c, committed) = digit().parse_stream(input).into_result()?;
+            if (c as u8 - b'0') % 2 == 0 {
+                Ok((c, committed))
+            } else {
+                let mut err = Errors::new(position, Error::Expected("even digit".into()));
+                err.add_error(Error::Unexpected(c.into()));
+                // `digit` consumed the digit so the error must be reported as committed
+                Err(committed.map(|_| err.into()))
+            }
+        });
+
+        let err = even_digit
+            .parse(easy::Stream(position::Stream::new("3")))
+            .unwrap_err();
+        assert_eq!(err.position.line, 1);
+        assert_eq!(err.position.column, 1);
+        assert_eq!(
+            err.errors,
+            [
+                Error::Expected("even digit".into()),
+                Error::Unexpected('3'.into())
+            ]
+        );
+
+        fn run(
+            parser: &mut dyn Parser<Input, Output = char, PartialState = ()>,
+        ) -> Result<char, Box<dyn std::error::Error>> {
+            let (c, _) = parser.parse(easy::Stream(position::Stream::new("3")))?;
+            Ok(c)
+        }
+        assert!(run(&mut even_digit).is_err());
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum Lexeme {
+        Number(i64),
+        Plus,
+    }
+
+    #[test]
+    fn errors_report_unexpected_tokens_of_slices() {
+        use combine::{easy::Info, parser::token::satisfy_map};
+
+        let number = || {
+            satisfy_map(|t| match t {
+                Lexeme::Number(n) => Some(n),
+                _ => None,
+            })
+        };
+        let mut parser = (number(), token(Lexeme::Plus), number());
+
+        let input = [Lexeme::Number(1), Lexeme::Plus, Lexeme::Number(2)];
+        assert_eq!(
+            parser
+                .easy_parse(position::Stream::new(&input[..]))
+                .map(|t| t.0),
+            Ok((1, Lexeme::Plus, 2))
+        );
+
+        let input = [Lexeme::Number(1), Lexeme::Number(2)];
+        assert_eq!(
+            parser.easy_parse(position::Stream::new(&input[..])),
+            Err(Errors {
+                position: 1,
+                errors: vec![
+                    Error::Unexpected(Info::Token(Lexeme::Number(2))),
+                    Error::Expected(Info::Token(Lexeme::Plus)),
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn satisfy_map_does_not_consume_on_none() {
+        use combine::parser::token::satisfy_map;
+
+        let number = || {
+            satisfy_map(|t| match t {
+                Lexeme::Number(n) => Some(n),
+                _ => None,
+            })
+        };
+
+        let input = [Lexeme::Plus, Lexeme::Number(2)];
+        assert_eq!(optional(number()).parse(&input[..]), Ok((None, &input[..])));
+        assert_eq!(
+            number()
+                .or(token(Lexeme::Plus).map(|_| 0))
+                .parse(&input[..]),
+            Ok((0, &input[1..]))
+        );
+        assert_eq!(number().parse(&input[1..]), Ok((2, &input[2..])));
+    }
+
+    #[test]
+    fn tokens_cmp_on_token_slices() {
+        use combine::tokens_cmp;
+        use std::mem::discriminant;
+
+        // Match the shape of a binary expression, ignoring the numbers themselves
+        let pattern = [Lexeme::Number(0), Lexeme::Plus, Lexeme::Number(0)];
+        let mut parser = tokens_cmp(&pattern[..], |l, r| discriminant(l) == discriminant(&r));
+
+        let input = [Lexeme::Number(1), Lexeme::Plus, Lexeme::Number(2)];
+        assert_eq!(
+            parser
+                .easy_parse(position::Stream::new(&input[..]))
+                .map(|t| t.1.input),
+            Ok(&input[3..])
+        );
+
+        let input = [Lexeme::Number(1), Lexeme::Plus, Lexeme::Plus];
+        assert_eq!(
+            parser
+                .easy_parse(position::Stream::new(&input[..]))
+                .map_err(|err| err.position),
+            Err(2)
+        );
+    }
+
+    #[test]
+    fn satisfy_and_any_on_token_slices() {
+        use combine::parser::token::satisfy;
+
+        let input = [Lexeme::Number(1), Lexeme::Plus, Lexeme::Plus];
+        let mut parser = (
+            any(),
+            many::<Vec<_>, _, _>(satisfy(|t| t == Lexeme::Plus)),
+            eof(),
+        );
+        assert_eq!(
+            parser
+                .easy_parse(position::Stream::new(&input[..]))
+                .map(|(output, rest)| (output, rest.input)),
+            Ok((
+                (Lexeme::Number(1), vec![Lexeme::Plus, Lexeme::Plus], ()),
+                &input[3..]
+            ))
+        );
+
+        assert_eq!(
+            satisfy(|b: u8| b.is_ascii_uppercase()).parse(&b"Ab"[..]),
+            Ok((b'A', &b"b"[..]))
+        );
+    }
+
+    #[test]
+    fn any_pulls_tokens_of_any_type() {
+        let input = [Lexeme::Plus, Lexeme::Number(3)];
+        assert_eq!(
+            many::<Vec<_>, _, _>(any()).parse(&input[..]),
+            Ok((vec![Lexeme::Plus, Lexeme::Number(3)], &input[2..]))
+        );
+
+        let input = [Lexeme::Plus];
+        assert_eq!(
+            (any(), any()).easy_parse(position::Stream::new(&input[..])),
+            Err(Errors {
+                position: 1,
+                errors: vec![Error::end_of_input()],
+            })
+        );
+    }
+
+    #[test]
+    fn binary_header() {
+        use combine::parser::byte::num::be_u16;
+
+        let mut header = bytes(b"HDR")
+            .with(be_u16())
+            .then(|len| range::take(usize::from(len)));
+
+        assert_eq!(
+            header
+                .easy_parse(position::Stream::new(&b"HDR\x00\x03abcrest"[..]))
+                .map(|(payload, rest)| (payload, rest.input)),
+            Ok((&b"abc"[..], &b"rest"[..]))
+        );
+
+        let err = header
+            .easy_parse(position::Stream::new(&b"HDX\x00\x03abc"[..]))
+            .unwrap_err();
+        assert_eq!(err.position, 2);
+
+        let err = header
+            .easy_parse(position::Stream::new(&b"HDR\x00\x05ab"[..]))
+            .unwrap_err();
+        assert_eq!(err.position, 5);
+        assert!(err.errors.contains(&Error::end_of_input()));
+    }
+
+    #[test]
+    fn keyword_not_followed_by_identifier_character() {
+        use combine::parser::char::alpha_num;
+
+        let mut keyword = string("if").skip(not_followed_by(alpha_num()));
+        assert_eq!(
+            keyword
+                .easy_parse(position::Stream::new("if("))
+                .map(|(keyword, rest)| (keyword, rest.input)),
+            Ok(("if", "("))
+        );
+        assert_eq!(
+            keyword.easy_parse(position::Stream::new("ifoo")),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 3 },
+                errors: vec![Error::Unexpected('o'.into())],
+            })
+        );
+
+        // The output of the parser which should not have matched is reported as unexpected
+        let mut keyword = string("let").skip(not_followed_by(string("ter")));
+        assert_eq!(
+            keyword.easy_parse(position::Stream::new("letter")),
+            Err(Errors {
+                position: SourcePosition { line: 1, column: 4 },
+                errors: vec![
+                    Error::Unexpected("ter".into()),
+                    Error::Unexpected('t'.into())
+                ],
+            })
+        );
+
+        let mut parser = combine::look_ahead(string("abc")).and(string("abc"));
+        assert_eq!(parser.parse("abc"), Ok((("abc", "abc"), "")));
+    }
+
+    #[test]
+    fn inline_parsers_from_closures_and_values() {
+        use combine::{parser, parser::error::unexpected_any, value};
+
+        let max = 5;
+        let mut small_digit = digit().then(move |d| {
+            if d.to_digit(10).unwrap() <= max {
+                value(d).left()
+            } else {
+                unexpected_any("large digit").right()
+            }
+        });
+        assert_eq!(small_digit.parse("3"), Ok(('3', "")));
+        assert!(small_digit.parse("7").is_err());
+
+        let mut calls = 0;
+        let result = char('#')
+            .with(parser(|input: &mut &str| {
+                calls += 1;
+                digit().parse_stream(input).into_result()
+            }))
+            .parse("#1");
+        assert_eq!(result, Ok(('1', "")));
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn collect_into_other_containers() {
+        use std::collections::HashMap;
+
+        assert_eq!(
+            many1::<String, _, _>(letter()).parse("ident1"),
+            Ok(("ident".to_string(), "1"))
+        );
+
+        let pair = (letter(), char('='), digit()).map(|(k, _, v)| (k, v));
+        let mut map = sep_by::<HashMap<_, _>, _, _, _>(pair, char(','));
+        let (map, rest) = map.parse("a=1,b=2,a=3").unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(map.len(), 2);
+        assert_eq!(map[&'a'], '3');
+        assert_eq!(map[&'b'], '2');
+    }
+
+    #[test]
+    fn iterate_over_records() {
+        use combine::parser::char::{integer, newline};
+
+        let record = || integer().skip(newline());
+
+        let mut input = easy::Stream(position::Stream::new("1\n2\n3\n"));
+        let mut iter = record().iter(&mut input);
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.next(), None);
+        assert!(iter.into_error().is_none());
+        assert_eq!(input.0.input, "");
+
+        // The iterator stops at the first record which fails to parse, leaving the input there
+        let mut input = easy::Stream(position::Stream::new("1\n2\nx\n"));
+        let mut iter = record().iter(&mut input);
+        assert_eq!((&mut iter).collect::<Vec<_>>(), [1, 2]);
+        let err = iter.into_error().unwrap();
+        assert_eq!(err.position, SourcePosition { line: 3, column: 1 });
+        assert_eq!(
+            err.errors,
+            vec![
+                Error::Unexpected('x'.into()),
+                Error::Expected("integer".into()),
+                Error::Expected("lf newline".into()),
+            ]
         );
+        assert_eq!(input.0.input, "x\n");
+
+        // A record which fails after committing input is reported as an error
+        let mut input = easy::Stream(position::Stream::new("1\n2x\n"));
+        let mut iter = record().iter(&mut input);
+        assert_eq!((&mut iter).collect::<Vec<_>>(), [1]);
+        let err = iter.into_result(()).unwrap_err().into_inner().error;
+        assert_eq!(err.position, SourcePosition { line: 2, column: 2 });
     }
 }
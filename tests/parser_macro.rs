@@ -34,3 +34,31 @@ fn test_that_we_dont_need_imports_for_this_macro_to_work() {
     test::<&str>();
     test_that_parsers_with_unnamed_types_can_be_in_same_scope::<&str>();
 }
+
+parser! {
+    fn array_depth[Input](max_depth: usize)(Input) -> usize
+        where [Input: ::combine::Stream<Token = char>]
+    {
+        use combine::{
+            between, optional,
+            parser::{char::char, error::unexpected_any},
+        };
+
+        let inner = if *max_depth <= 1 {
+            unexpected_any("nested array").left()
+        } else {
+            array_depth(*max_depth - 1).right()
+        };
+        between(char('['), char(']'), optional(inner))
+            .map(|inner: Option<usize>| inner.map_or(1, |depth| depth + 1))
+    }
+}
+
+#[test]
+fn recursive_parser_with_argument() {
+    use combine::Parser;
+
+    assert_eq!(array_depth(3).parse("[[[]]]"), Ok((3, "")));
+    assert_eq!(array_depth(3).parse("[[]]"), Ok((2, "")));
+    assert!(array_depth(3).parse("[[[[]]]]").is_err());
+}